@@ -1,10 +1,33 @@
 mod branch_kind;
 pub mod constant;
 mod coupling_kind;
+mod dunham_coefficients;
+mod inversion_symmetry_kind;
+mod nuclear_spin_kind;
+mod parity_kind;
+mod potential_kind;
+mod rotational_distribution_kind;
+mod rotational_term_kind;
 mod spin_quantum_number;
+mod symmetry_kind;
 mod term;
+mod transition_moment_kind;
+mod vibrational_distribution_kind;
 
 pub use branch_kind::*;
 pub use coupling_kind::CouplingKind;
+pub use dunham_coefficients::{
+    AtomicMasses, BornOppenheimerBreakdown, DunhamCoefficients, DunhamCoefficientsError,
+    SpectroscopicConstants,
+};
+pub use inversion_symmetry_kind::InversionSymmetryKind;
+pub use nuclear_spin_kind::NuclearSpinKind;
+pub use parity_kind::ParityKind;
+pub use potential_kind::PotentialKind;
+pub use rotational_distribution_kind::RotationalDistributionKind;
+pub use rotational_term_kind::RotationalTermKind;
 pub use spin_quantum_number::SpinQuantumNumberKind;
+pub use symmetry_kind::SymmetryKind;
 pub use term::Term;
+pub use transition_moment_kind::TransitionMomentKind;
+pub use vibrational_distribution_kind::VibrationalDistributionKind;
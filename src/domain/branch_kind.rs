@@ -5,6 +5,32 @@ pub enum SingletBranchKind {
     R1,
 }
 
+impl SingletBranchKind {
+    /// Returns `ΔJ = J' - J''` of the branch
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::SingletBranchKind;
+    /// assert_eq!(SingletBranchKind::P1.delta_j(), -1.0);
+    /// ```
+    pub fn delta_j(&self) -> f64 {
+        match self {
+            Self::P1 => -1.0,
+            Self::Q1 => 0.0,
+            Self::R1 => 1.0,
+        }
+    }
+
+    /// Returns `(i, k)` of the upper level `Fi'` and the lower level `Fk''`
+    pub fn levels(&self) -> (usize, usize) {
+        (1, 1)
+    }
+}
+
+/// Branches `Fi' - Fk''` of a doublet system.
+/// The satellites are named after `ΔN` with `F1: N = J-1/2` and `F2: N = J+1/2`,
+/// e.g. `O12` is `^O P12` (`ΔN = -2`, `ΔJ = -1`) and `S21` is `^S R21`.
 #[derive(Debug, Clone)]
 pub enum DoubletBranchKind {
     P1,
@@ -13,6 +39,54 @@ pub enum DoubletBranchKind {
     P2,
     Q2,
     R2,
+    /// `^O P12`
+    O12,
+    /// `^P Q12`
+    P12,
+    /// `^Q R12`
+    Q12,
+    /// `^Q P21`
+    Q21,
+    /// `^R Q21`
+    R21,
+    /// `^S R21`
+    S21,
+}
+
+impl DoubletBranchKind {
+    /// Returns `ΔJ = J' - J''` of the branch
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::DoubletBranchKind;
+    /// assert_eq!(DoubletBranchKind::R2.delta_j(), 1.0);
+    /// assert_eq!(DoubletBranchKind::O12.delta_j(), -1.0);
+    /// ```
+    pub fn delta_j(&self) -> f64 {
+        match self {
+            Self::P1 | Self::P2 | Self::O12 | Self::Q21 => -1.0,
+            Self::Q1 | Self::Q2 | Self::P12 | Self::R21 => 0.0,
+            Self::R1 | Self::R2 | Self::Q12 | Self::S21 => 1.0,
+        }
+    }
+
+    /// Returns `(i, k)` of the upper level `Fi'` and the lower level `Fk''`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::DoubletBranchKind;
+    /// assert_eq!(DoubletBranchKind::O12.levels(), (1, 2));
+    /// ```
+    pub fn levels(&self) -> (usize, usize) {
+        match self {
+            Self::P1 | Self::Q1 | Self::R1 => (1, 1),
+            Self::P2 | Self::Q2 | Self::R2 => (2, 2),
+            Self::O12 | Self::P12 | Self::Q12 => (1, 2),
+            Self::Q21 | Self::R21 | Self::S21 => (2, 1),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -27,3 +101,98 @@ pub enum TripletBranchKind {
     Q3,
     R3,
 }
+
+impl TripletBranchKind {
+    /// Returns `ΔJ = J' - J''` of the branch
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::TripletBranchKind;
+    /// assert_eq!(TripletBranchKind::Q3.delta_j(), 0.0);
+    /// ```
+    pub fn delta_j(&self) -> f64 {
+        match self {
+            Self::P1 | Self::P2 | Self::P3 => -1.0,
+            Self::Q1 | Self::Q2 | Self::Q3 => 0.0,
+            Self::R1 | Self::R2 | Self::R3 => 1.0,
+        }
+    }
+
+    /// Returns `(i, k)` of the upper level `Fi'` and the lower level `Fk''`
+    pub fn levels(&self) -> (usize, usize) {
+        match self {
+            Self::P1 | Self::Q1 | Self::R1 => (1, 1),
+            Self::P2 | Self::Q2 | Self::R2 => (2, 2),
+            Self::P3 | Self::Q3 | Self::R3 => (3, 3),
+        }
+    }
+}
+
+/// Branch of a transition, tagged with the multiplicity of the system
+///
+/// # Examples
+///
+/// ```
+/// use emission_spectrum_lib_rs::prelude::{BranchKind, SingletBranchKind};
+/// let b: BranchKind = SingletBranchKind::R1.into();
+/// assert_eq!(b.delta_j(), 1.0);
+/// let b = BranchKind::General { i: 4, k: 3, dj: -1.0 };
+/// assert_eq!((b.delta_j(), b.levels()), (-1.0, (4, 3)));
+/// ```
+#[derive(Debug, Clone)]
+pub enum BranchKind {
+    Singlet(SingletBranchKind),
+    Doublet(DoubletBranchKind),
+    Triplet(TripletBranchKind),
+    /// `Fi'(J''+ΔJ) - Fk''(J'')` of any multiplicity (e.g. quartets), with the
+    /// Hönl-London factor from `honl_london_factor::general`
+    /// * `i` - `i` of the upper level `Fi'` (`1..=2S+1`)
+    /// * `k` - `k` of the lower level `Fk''` (`1..=2S+1`)
+    /// * `dj` - `ΔJ = J' - J''`
+    General {
+        i: usize,
+        k: usize,
+        dj: f64,
+    },
+}
+
+impl BranchKind {
+    /// Returns `ΔJ = J' - J''` of the branch
+    pub fn delta_j(&self) -> f64 {
+        match self {
+            Self::Singlet(b) => b.delta_j(),
+            Self::Doublet(b) => b.delta_j(),
+            Self::Triplet(b) => b.delta_j(),
+            Self::General { dj, .. } => *dj,
+        }
+    }
+
+    /// Returns `(i, k)` of the upper level `Fi'` and the lower level `Fk''`
+    pub fn levels(&self) -> (usize, usize) {
+        match self {
+            Self::Singlet(b) => b.levels(),
+            Self::Doublet(b) => b.levels(),
+            Self::Triplet(b) => b.levels(),
+            Self::General { i, k, .. } => (*i, *k),
+        }
+    }
+}
+
+impl From<SingletBranchKind> for BranchKind {
+    fn from(item: SingletBranchKind) -> Self {
+        Self::Singlet(item)
+    }
+}
+
+impl From<DoubletBranchKind> for BranchKind {
+    fn from(item: DoubletBranchKind) -> Self {
+        Self::Doublet(item)
+    }
+}
+
+impl From<TripletBranchKind> for BranchKind {
+    fn from(item: TripletBranchKind) -> Self {
+        Self::Triplet(item)
+    }
+}
@@ -4,3 +4,11 @@ pub const C: f64 = 299_792_458.0;
 pub const H: f64 = 6.62607015e-34;
 /// Elementary Charge (C)
 pub const E: f64 = 1.60217663e-19;
+/// Electron Mass (u)
+pub const M_E: f64 = 5.485_799_090_65e-4;
+/// Atomic Mass Unit (kg)
+pub const U: f64 = 1.660_539_066_60e-27;
+/// Vacuum Permittivity (F/m)
+pub const EPSILON_0: f64 = 8.854_187_812_8e-12;
+/// Debye (Cm)
+pub const DEBYE: f64 = 1.0e-21 / C;
@@ -2,4 +2,11 @@
 pub enum CouplingKind {
     A,
     B,
+    /// Intermediate coupling between Hund's case (a) and (b)
+    /// * `yu` - `Y' = A'/B'`: Upper coupling parameter
+    /// * `yl` - `Y'' = A''/B''`: Lower coupling parameter
+    Intermediate {
+        yu: f64,
+        yl: f64,
+    },
 }
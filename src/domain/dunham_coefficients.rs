@@ -0,0 +1,446 @@
+use std::fmt;
+
+use crate::domain::constant::M_E;
+
+type Params = Vec<Vec<f64>>;
+
+/// Conventional spectroscopic constants of an electronic state (`cm^-1`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpectroscopicConstants {
+    /// `ωe`: Vibrational constant
+    pub we: f64,
+    /// `ωexe`: Anharmonicity constant
+    pub wexe: f64,
+    /// `ωeye`: Anharmonicity constant
+    pub weye: f64,
+    /// `Be`: Rotational constant
+    pub be: f64,
+    /// `αe`: Vibration-rotation interaction constant
+    pub alpha_e: f64,
+    /// `γe`: Vibration-rotation interaction constant
+    pub gamma_e: f64,
+    /// `De`: Centrifugal distortion constant
+    pub de: f64,
+    /// `βe`: Vibrational dependence of `De`
+    pub beta_e: f64,
+    /// `He`: Centrifugal distortion constant
+    pub he: f64,
+}
+
+/// Atomic masses of a diatomic molecule `AB` (u)
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtomicMasses {
+    /// `MA`: Mass of the atom `A`
+    pub a: f64,
+    /// `MB`: Mass of the atom `B`
+    pub b: f64,
+}
+
+impl AtomicMasses {
+    /// Returns `μ = MA MB / (MA + MB)`: Reduced mass (u)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::AtomicMasses;
+    /// let m = AtomicMasses { a: 2.0, b: 2.0 };
+    /// assert_eq!(m.reduced_mass(), 1.0);
+    /// ```
+    pub fn reduced_mass(&self) -> f64 {
+        self.a * self.b / (self.a + self.b)
+    }
+}
+
+/// Born-Oppenheimer breakdown corrections `ΔA_kl` and `ΔB_kl` of
+/// `Y_kl = U_kl μ^-(k/2+l) (1 + me/MA ΔA_kl + me/MB ΔB_kl)` (dimensionless)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BornOppenheimerBreakdown {
+    /// `ΔA_kl` of the atom `A`
+    pub delta_a: DunhamCoefficients,
+    /// `ΔB_kl` of the atom `B`
+    pub delta_b: DunhamCoefficients,
+}
+
+/// Error of the shape or the values of `DunhamCoefficients`
+#[derive(Debug, Clone, PartialEq)]
+pub enum DunhamCoefficientsError {
+    /// No rows, `Y_k0` is required
+    Empty,
+    /// `Y_kl` is `NaN` or infinite
+    NonFinite { k: usize, l: usize },
+}
+
+impl fmt::Display for DunhamCoefficientsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Dunham coefficients have no rows"),
+            Self::NonFinite { k, l } => write!(f, "Y_{}{} is not finite", k, l),
+        }
+    }
+}
+
+impl std::error::Error for DunhamCoefficientsError {}
+
+/// Dunham coefficients `Y_kl` (`cm^-1`) of
+/// `E(v, J) = Σ Y_kl (v+1/2)^k (J(J+1))^l`
+///
+/// |k\l|0      |1    |2    |3   |4   |
+/// |---|-------|-----|-----|----|----|
+/// |0  |       |`Be` |`-De`|`He`|`Le`|
+/// |1  |`ωe`   |`-αe`|`-βe`|    |    |
+/// |2  |`-ωexe`|`γe` |     |    |    |
+/// |3  |`ωeye` |     |     |    |    |
+/// |4  |`ωeze` |     |     |    |    |
+///
+/// # Examples
+///
+/// ```
+/// use emission_spectrum_lib_rs::prelude::{DunhamCoefficients, SpectroscopicConstants};
+/// let y = DunhamCoefficients::from(SpectroscopicConstants {
+///     we: 1153.3,
+///     wexe: 19.48,
+///     be: 1.320,
+///     alpha_e: 0.022,
+///     ..Default::default()
+/// });
+/// assert_eq!(y.get(2, 0), -19.48);
+/// assert_eq!(y.alpha_e(), 0.022);
+/// assert_eq!(y.to_vec(), vec![vec![0.0, 1153.3, -19.48], vec![1.320, -0.022]]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DunhamCoefficients(Params);
+
+impl DunhamCoefficients {
+    /// Returns the new Dunham coefficients with all `Y_kl = 0`
+    pub fn new() -> Self {
+        Self(vec![vec![]])
+    }
+
+    /// Returns the coefficients with `Y_kl` set to `y`, consuming `self` value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::DunhamCoefficients;
+    /// let y = DunhamCoefficients::new().with(1, 0, 2000.0).with(0, 1, 1.9);
+    /// assert_eq!(y.to_vec(), vec![vec![0.0, 2000.0], vec![1.9]]);
+    /// ```
+    pub fn with(mut self, k: usize, l: usize, y: f64) -> Self {
+        if self.0.len() <= l {
+            self.0.resize(l + 1, vec![]);
+        }
+        if self.0[l].len() <= k {
+            self.0[l].resize(k + 1, 0.0);
+        }
+        self.0[l][k] = y;
+        self
+    }
+
+    /// Returns `Y_kl`, `0` if it is not given
+    pub fn get(&self, k: usize, l: usize) -> f64 {
+        self.0
+            .get(l)
+            .and_then(|vec| vec.get(k))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the coefficients in the layout of `DunhamExpansion` (`[l][k]`)
+    pub fn to_vec(&self) -> Params {
+        self.0.clone()
+    }
+
+    /// Returns the coefficients of another isotopologue, scaled by the reduced mass as
+    /// `Y_kl ∝ μ^-(k/2+l)`
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Atomic masses of the isotopologue of `self`
+    /// * `to` - Atomic masses of the other isotopologue
+    /// * `breakdown` - Born-Oppenheimer breakdown corrections, if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{AtomicMasses, DunhamCoefficients};
+    /// // ¹²CF to ¹³CF
+    /// let y = DunhamCoefficients::new().with(1, 0, 1308.1).with(0, 1, 1.4172);
+    /// let c12f = AtomicMasses { a: 12.0, b: 18.998403163 };
+    /// let c13f = AtomicMasses { a: 13.003354835, b: 18.998403163 };
+    /// let res = y.isotopologue(&c12f, &c13f, None);
+    /// let rho = (c12f.reduced_mass() / c13f.reduced_mass()).sqrt();
+    /// assert!((res.we() - 1308.1 * rho).abs() < 1e-9);
+    /// assert!((res.be() - 1.4172 * rho.powi(2)).abs() < 1e-12);
+    /// ```
+    pub fn isotopologue(
+        &self,
+        from: &AtomicMasses,
+        to: &AtomicMasses,
+        breakdown: Option<&BornOppenheimerBreakdown>,
+    ) -> Self {
+        let rho = (from.reduced_mass() / to.reduced_mass()).sqrt();
+        let correction = |m: &AtomicMasses, k: usize, l: usize| {
+            breakdown.map_or(1.0, |d| {
+                1.0 + M_E / m.a * d.delta_a.get(k, l) + M_E / m.b * d.delta_b.get(k, l)
+            })
+        };
+        let res = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(l, vec)| {
+                vec.iter()
+                    .enumerate()
+                    .map(|(k, &y)| {
+                        y * rho.powi((k + 2 * l) as i32) * correction(to, k, l)
+                            / correction(from, k, l)
+                    })
+                    .collect()
+            })
+            .collect();
+        Self(res)
+    }
+
+    /// Returns `ωe = Y10`
+    pub fn we(&self) -> f64 {
+        self.get(1, 0)
+    }
+
+    /// Returns `ωexe = -Y20`
+    pub fn wexe(&self) -> f64 {
+        -self.get(2, 0)
+    }
+
+    /// Returns `ωeye = Y30`
+    pub fn weye(&self) -> f64 {
+        self.get(3, 0)
+    }
+
+    /// Returns `Be = Y01`
+    pub fn be(&self) -> f64 {
+        self.get(0, 1)
+    }
+
+    /// Returns `αe = -Y11`
+    pub fn alpha_e(&self) -> f64 {
+        -self.get(1, 1)
+    }
+
+    /// Returns `γe = Y21`
+    pub fn gamma_e(&self) -> f64 {
+        self.get(2, 1)
+    }
+
+    /// Returns `De = -Y02`
+    pub fn de(&self) -> f64 {
+        -self.get(0, 2)
+    }
+
+    /// Returns `βe = -Y12`
+    pub fn beta_e(&self) -> f64 {
+        -self.get(1, 2)
+    }
+
+    /// Returns `He = Y03`
+    pub fn he(&self) -> f64 {
+        self.get(0, 3)
+    }
+}
+
+impl Default for DunhamCoefficients {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<SpectroscopicConstants> for DunhamCoefficients {
+    fn from(c: SpectroscopicConstants) -> Self {
+        [
+            (1, 0, c.we),
+            (2, 0, -c.wexe),
+            (3, 0, c.weye),
+            (0, 1, c.be),
+            (1, 1, -c.alpha_e),
+            (2, 1, c.gamma_e),
+            (0, 2, -c.de),
+            (1, 2, -c.beta_e),
+            (0, 3, c.he),
+        ]
+        .into_iter()
+        .filter(|&(_, _, y)| y != 0.0)
+        .fold(Self::new(), |res, (k, l, y)| res.with(k, l, y))
+    }
+}
+
+impl TryFrom<Params> for DunhamCoefficients {
+    type Error = DunhamCoefficientsError;
+
+    /// Returns the coefficients from the layout of `DunhamExpansion` (`[l][k]`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{DunhamCoefficients, DunhamCoefficientsError};
+    /// let y = DunhamCoefficients::try_from(vec![vec![0.0, 1308.1], vec![1.4172]]).unwrap();
+    /// assert_eq!(y.be(), 1.4172);
+    /// let e = DunhamCoefficients::try_from(vec![vec![0.0, f64::NAN]]);
+    /// assert_eq!(e, Err(DunhamCoefficientsError::NonFinite { k: 1, l: 0 }));
+    /// ```
+    fn try_from(params: Params) -> Result<Self, Self::Error> {
+        if params.is_empty() {
+            return Err(DunhamCoefficientsError::Empty);
+        }
+        for (l, vec) in params.iter().enumerate() {
+            if let Some(k) = vec.iter().position(|y| !y.is_finite()) {
+                return Err(DunhamCoefficientsError::NonFinite { k, l });
+            }
+        }
+        Ok(Self(params))
+    }
+}
+
+impl From<DunhamCoefficients> for Params {
+    fn from(y: DunhamCoefficients) -> Self {
+        y.0
+    }
+}
+
+impl fmt::Display for DunhamCoefficients {
+    /// Writes the coefficients as the table of `k` rows and `l` columns
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ls = self.0.len();
+        let ks = self.0.iter().map(Vec::len).max().unwrap_or(0);
+
+        write!(f, "|k\\l|")?;
+        for l in 0..ls {
+            write!(f, "{}|", l)?;
+        }
+        writeln!(f)?;
+        write!(f, "|---|")?;
+        for _ in 0..ls {
+            write!(f, "---|")?;
+        }
+        for k in 0..ks {
+            writeln!(f)?;
+            write!(f, "|{}|", k)?;
+            for vec in self.0.iter() {
+                match vec.get(k) {
+                    Some(y) if *y != 0.0 => write!(f, "{}|", y)?,
+                    _ => write!(f, "|")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AtomicMasses, BornOppenheimerBreakdown, DunhamCoefficients, DunhamCoefficientsError,
+        SpectroscopicConstants,
+    };
+    use crate::domain::constant::M_E;
+
+    #[test]
+    fn from_spectroscopic_constants() {
+        let c = SpectroscopicConstants {
+            we: 1308.1,
+            wexe: 11.10,
+            weye: 0.093,
+            be: 1.4172,
+            alpha_e: 0.0184,
+            gamma_e: 0.00011,
+            de: 6.5e-6,
+            beta_e: 1.0e-8,
+            he: 1.0e-12,
+        };
+        let y = DunhamCoefficients::from(c.clone());
+        assert_eq!(
+            y.to_vec(),
+            vec![
+                vec![0.0, 1308.1, -11.10, 0.093],
+                vec![1.4172, -0.0184, 0.00011],
+                vec![-6.5e-6, -1.0e-8],
+                vec![1.0e-12],
+            ]
+        );
+
+        let res = SpectroscopicConstants {
+            we: y.we(),
+            wexe: y.wexe(),
+            weye: y.weye(),
+            be: y.be(),
+            alpha_e: y.alpha_e(),
+            gamma_e: y.gamma_e(),
+            de: y.de(),
+            beta_e: y.beta_e(),
+            he: y.he(),
+        };
+        assert_eq!(res, c);
+    }
+
+    #[test]
+    fn try_from() {
+        assert_eq!(
+            DunhamCoefficients::try_from(vec![]),
+            Err(DunhamCoefficientsError::Empty)
+        );
+        assert_eq!(
+            DunhamCoefficients::try_from(vec![vec![0.0, 1.0], vec![1.0, f64::INFINITY]]),
+            Err(DunhamCoefficientsError::NonFinite { k: 1, l: 1 })
+        );
+        let y = DunhamCoefficients::try_from(vec![vec![0.0, 1.0], vec![], vec![-1.0]]).unwrap();
+        assert_eq!(y.get(1, 0), 1.0);
+        assert_eq!(y.get(0, 1), 0.0);
+        assert_eq!(y.de(), 1.0);
+        assert_eq!(y.get(5, 5), 0.0);
+    }
+
+    #[test]
+    fn display() {
+        let y = DunhamCoefficients::try_from(vec![vec![0.0, 1153.3, -19.48], vec![1.32]]).unwrap();
+        assert_eq!(
+            y.to_string(),
+            "|k\\l|0|1|\n|---|---|---|\n|0||1.32|\n|1|1153.3||\n|2|-19.48||"
+        );
+    }
+
+    #[test]
+    fn isotopologue() {
+        // OH X²Π to OD X²Π
+        let (h, d, o) = (1.00782503207, 2.0141017778, 15.99491461956);
+        let oh = AtomicMasses { a: o, b: h };
+        let od = AtomicMasses { a: o, b: d };
+        let y = DunhamCoefficients::from(SpectroscopicConstants {
+            we: 3737.76,
+            wexe: 84.881,
+            be: 18.911,
+            alpha_e: 0.7242,
+            de: 19.38e-4,
+            ..Default::default()
+        });
+        let res = y.isotopologue(&oh, &od, None);
+        assert!((res.we() - 2720.24).abs() / 2720.24 < 1e-3);
+        let rho = (oh.reduced_mass() / od.reduced_mass()).sqrt();
+        assert!((res.wexe() - 84.881 * rho.powi(2)).abs() < 1e-9);
+        assert!((res.alpha_e() - 0.7242 * rho.powi(3)).abs() < 1e-12);
+        assert!((res.de() - 19.38e-4 * rho.powi(4)).abs() < 1e-15);
+
+        // Back to OH
+        let back = res.isotopologue(&od, &oh, None);
+        for (k, l) in [(1, 0), (2, 0), (0, 1), (1, 1), (0, 2)] {
+            assert!((back.get(k, l) - y.get(k, l)).abs() < 1e-9 * y.get(k, l).abs());
+        }
+
+        // Born-Oppenheimer breakdown of `Y01` by the hydrogen atom
+        let breakdown = BornOppenheimerBreakdown {
+            delta_a: DunhamCoefficients::new(),
+            delta_b: DunhamCoefficients::new().with(0, 1, -2.0),
+        };
+        let res_bo = y.isotopologue(&oh, &od, Some(&breakdown));
+        let e = (1.0 - 2.0 * M_E / d) / (1.0 - 2.0 * M_E / h);
+        assert!((res_bo.be() / res.be() - e).abs() < 1e-12);
+        assert_eq!(res_bo.we(), res.we());
+    }
+}
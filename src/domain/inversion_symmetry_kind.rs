@@ -0,0 +1,6 @@
+/// Inversion symmetry of an electronic state of a homonuclear molecule (`g` or `u`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InversionSymmetryKind {
+    G,
+    U,
+}
@@ -0,0 +1,96 @@
+use crate::domain::{InversionSymmetryKind, ParityKind};
+
+/// Nuclear spin statistics of the rotational levels
+#[derive(Debug, Clone, Default)]
+pub enum NuclearSpinKind {
+    /// No nuclear spin statistics, all the levels have the weight `1`
+    #[default]
+    Heteronuclear,
+    /// Homonuclear molecule of two nuclei with the nuclear spin `I`
+    /// * `i` - `I`: Nuclear spin
+    /// * `inversion_symmetry_kind` - `g` or `u` of the electronic state
+    Homonuclear {
+        i: f64,
+        inversion_symmetry_kind: InversionSymmetryKind,
+    },
+}
+
+impl NuclearSpinKind {
+    /// Returns `gns`: Nuclear spin weight of the level `J` = `j` of `parity_kind`
+    ///
+    /// The levels of the total parity `+` in a `g` state and `-` in a `u` state are symmetric
+    /// in the exchange of the nuclei. The symmetric levels have `(I+1)(2I+1)` and the
+    /// antisymmetric ones `I(2I+1)` for integer `I` (bosons), and vice versa for
+    /// half-integer `I` (fermions).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::*;
+    /// // N2 X¹Σg+: 2:1 alternation of the even and odd `J`
+    /// let n2 = NuclearSpinKind::Homonuclear {
+    ///     i: 1.0,
+    ///     inversion_symmetry_kind: InversionSymmetryKind::G,
+    /// };
+    /// assert_eq!(n2.weight(2.0, ParityKind::E), 6.0);
+    /// assert_eq!(n2.weight(3.0, ParityKind::E), 3.0);
+    /// // C2 X¹Σg+ (12C): the odd `J` levels are missing
+    /// let c2 = NuclearSpinKind::Homonuclear {
+    ///     i: 0.0,
+    ///     inversion_symmetry_kind: InversionSymmetryKind::G,
+    /// };
+    /// assert_eq!(c2.weight(2.0, ParityKind::E), 1.0);
+    /// assert_eq!(c2.weight(3.0, ParityKind::E), 0.0);
+    /// assert_eq!(NuclearSpinKind::Heteronuclear.weight(3.0, ParityKind::E), 1.0);
+    /// ```
+    pub fn weight(&self, j: f64, parity_kind: ParityKind) -> f64 {
+        match self {
+            NuclearSpinKind::Heteronuclear => 1.0,
+            NuclearSpinKind::Homonuclear {
+                i,
+                inversion_symmetry_kind,
+            } => {
+                let symmetric = parity_kind.is_positive(j)
+                    == (*inversion_symmetry_kind == InversionSymmetryKind::G);
+                let bosons = i.fract() == 0.0;
+                if symmetric == bosons {
+                    (i + 1.0) * (2.0 * i + 1.0)
+                } else {
+                    i * (2.0 * i + 1.0)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::{InversionSymmetryKind, NuclearSpinKind, ParityKind};
+
+    #[test]
+    fn weight() {
+        let homonuclear = |i, inversion_symmetry_kind| NuclearSpinKind::Homonuclear {
+            i,
+            inversion_symmetry_kind,
+        };
+
+        // H2 X¹Σg+: para (even `J`) and ortho (odd `J`) hydrogen
+        let h2 = homonuclear(0.5, InversionSymmetryKind::G);
+        assert_eq!(h2.weight(0.0, ParityKind::E), 1.0);
+        assert_eq!(h2.weight(1.0, ParityKind::E), 3.0);
+
+        // 16O2 X³Σg-: only the odd `N`, the `e` levels `F1` and `F3` of even `J` and the `f`
+        // levels `F2` of odd `J`
+        let o2 = homonuclear(0.0, InversionSymmetryKind::G);
+        assert_eq!(o2.weight(2.0, ParityKind::E), 1.0); // N = 1, 3
+        assert_eq!(o2.weight(2.0, ParityKind::F), 0.0); // N = 2
+        assert_eq!(o2.weight(1.0, ParityKind::E), 0.0); // N = 0, 2
+        assert_eq!(o2.weight(1.0, ParityKind::F), 1.0); // N = 1
+
+        // The Λ-doublets of a `u` state alternate with `J`
+        let u = homonuclear(1.0, InversionSymmetryKind::U);
+        assert_eq!(u.weight(2.0, ParityKind::E), 3.0);
+        assert_eq!(u.weight(2.0, ParityKind::F), 6.0);
+        assert_eq!(u.weight(3.0, ParityKind::E), 6.0);
+    }
+}
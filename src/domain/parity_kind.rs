@@ -0,0 +1,42 @@
+/// `e`/`f` parity label of a rotational level
+///
+/// `e` levels have the total parity `+(-1)^(J-s)` and `f` levels `-(-1)^(J-s)`, with
+/// `s = 0` for integer `J` and `s = 1/2` for half-integer `J`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParityKind {
+    E,
+    F,
+}
+
+impl ParityKind {
+    /// Returns whether the electric dipole transition between the levels is allowed:
+    /// `e - e` and `f - f` for the P and R branches, `e - f` for the Q branches
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::ParityKind;
+    /// assert!(ParityKind::E.is_allowed(&ParityKind::E, 1.0));
+    /// assert!(!ParityKind::E.is_allowed(&ParityKind::E, 0.0));
+    /// assert!(ParityKind::F.is_allowed(&ParityKind::E, 0.0));
+    /// ```
+    pub fn is_allowed(&self, other: &ParityKind, delta_j: f64) -> bool {
+        (self == other) == (delta_j != 0.0)
+    }
+
+    /// Returns whether the total parity of the level `J` = `j` is `+`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::ParityKind;
+    /// assert!(ParityKind::E.is_positive(2.0));
+    /// assert!(!ParityKind::E.is_positive(1.5));
+    /// assert!(ParityKind::F.is_positive(1.5));
+    /// ```
+    pub fn is_positive(&self, j: f64) -> bool {
+        // `J - s`
+        let n = j.floor() as i64;
+        (n.rem_euclid(2) == 0) == (*self == ParityKind::E)
+    }
+}
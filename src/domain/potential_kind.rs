@@ -0,0 +1,288 @@
+use crate::domain::SpectroscopicConstants;
+
+/// Potential energy curve `U(r)` of an electronic state, measured from the minimum
+/// (`r` in Å, `U` in `cm^-1`)
+#[derive(Debug, Clone)]
+pub enum PotentialKind {
+    /// `U(r) = De(1 - exp(-a(r-re)))^2`
+    /// * `de` - `De`: Dissociation energy (`cm^-1`)
+    /// * `a` - `a`: Range parameter (`Å^-1`)
+    /// * `re` - `re`: Equilibrium internuclear distance (Å)
+    Morse { de: f64, a: f64, re: f64 },
+    /// `U(r) = De[(1 - exp(-x))^2 + cx^3(1 + bx)exp(-2x)]` with `x = a(r-re)`
+    /// * `de` - `De`: Dissociation energy (`cm^-1`)
+    /// * `a` - `a`: Range parameter (`Å^-1`)
+    /// * `re` - `re`: Equilibrium internuclear distance (Å)
+    /// * `b`, `c` - Dimensionless parameters
+    HulburtHirschfelder {
+        de: f64,
+        a: f64,
+        re: f64,
+        b: f64,
+        c: f64,
+    },
+    /// `U(r) = De(1 - exp(-β(r)(r-re)))^2` with `β(r) = Σ βi y^i` and
+    /// `y = (r^p - re^p)/(r^p + re^p)`
+    /// * `de` - `De`: Dissociation energy (`cm^-1`)
+    /// * `re` - `re`: Equilibrium internuclear distance (Å)
+    /// * `p` - `p`: Power of `y`
+    /// * `beta` - `[β0, β1, ...]` (`Å^-1`)
+    ExtendedMorse {
+        de: f64,
+        re: f64,
+        p: i32,
+        beta: Vec<f64>,
+    },
+    /// Natural cubic spline through the points `[(r, U(r))]` in ascending order of `r`
+    /// (e.g. `Rkr::eval`). Outside of the points the inner wall is extrapolated
+    /// exponentially and the outer wall linearly.
+    /// * `points` - `[(r, U(r))]`
+    /// * `m` - `U''` at the points, see `PotentialKind::tabulated`
+    Tabulated {
+        points: Vec<(f64, f64)>,
+        m: Vec<f64>,
+    },
+}
+
+impl PotentialKind {
+    /// Returns the Morse potential with `De = ωe^2/(4ωexe)` and `a = √(ωexe/Be)/re`,
+    /// which reproduces `ωe` and `ωexe`
+    ///
+    /// # Arguments
+    ///
+    /// * `constants` - `ωe`, `ωexe` and `Be` are used
+    /// * `re` - `re`: Equilibrium internuclear distance (Å)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{PotentialKind, SpectroscopicConstants};
+    /// let constants = SpectroscopicConstants { we: 2000.0, wexe: 20.0, be: 2.0, ..Default::default() };
+    /// let PotentialKind::Morse { de, a, .. } = PotentialKind::morse(&constants, 1.0) else { panic!() };
+    /// assert_eq!(de, 50000.0);
+    /// assert!((a - 10.0f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn morse(constants: &SpectroscopicConstants, re: f64) -> Self {
+        PotentialKind::Morse {
+            de: constants.we.powi(2) / (4.0 * constants.wexe),
+            a: (constants.wexe / constants.be).sqrt() / re,
+            re,
+        }
+    }
+
+    /// Returns the Hulburt-Hirschfelder potential, which reproduces `ωe`, `ωexe`, `Be` and
+    /// `αe` through the Dunham coefficients `a0`, `a1` and `a2`
+    ///
+    /// # Arguments
+    ///
+    /// * `constants` - `ωe`, `ωexe`, `Be` and `αe` are used
+    /// * `re` - `re`: Equilibrium internuclear distance (Å)
+    /// * `de` - `De`: Dissociation energy (`cm^-1`), `ωe^2/(4ωexe)` if `None`
+    pub fn hulburt_hirschfelder(
+        constants: &SpectroscopicConstants,
+        re: f64,
+        de: Option<f64>,
+    ) -> Self {
+        let de = de.unwrap_or(constants.we.powi(2) / (4.0 * constants.wexe));
+        let (a0, a1, a2) = dunham_potential(constants);
+        let c = 1.0 + a1 * (de / a0).sqrt();
+        let b = 2.0 - (7.0 / 12.0 - de * a2 / a0) / c;
+        PotentialKind::HulburtHirschfelder {
+            de,
+            a: (a0 / de).sqrt() / re,
+            re,
+            b,
+            c,
+        }
+    }
+
+    /// Returns the extended Morse oscillator with `p = 1` and `[β0, β1]`, which reproduces
+    /// `ωe`, `Be` and `αe` through the Dunham coefficients `a0` and `a1`
+    ///
+    /// # Arguments
+    ///
+    /// * `constants` - `ωe`, `ωexe`, `Be` and `αe` are used
+    /// * `re` - `re`: Equilibrium internuclear distance (Å)
+    /// * `de` - `De`: Dissociation energy (`cm^-1`), `ωe^2/(4ωexe)` if `None`
+    pub fn extended_morse(constants: &SpectroscopicConstants, re: f64, de: Option<f64>) -> Self {
+        let de = de.unwrap_or(constants.we.powi(2) / (4.0 * constants.wexe));
+        let (a0, a1, _) = dunham_potential(constants);
+        let beta0 = (a0 / de).sqrt() / re;
+        PotentialKind::ExtendedMorse {
+            de,
+            re,
+            p: 1,
+            beta: vec![beta0, beta0 * (a1 + beta0 * re)],
+        }
+    }
+
+    /// Returns the natural cubic spline through `points`, solving for the second derivatives
+    /// once
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - `[(r (Å), U(r) (cm^-1))]` in ascending order of `r`
+    pub fn tabulated(points: Vec<(f64, f64)>) -> Self {
+        let m = second_derivatives(&points);
+        PotentialKind::Tabulated { points, m }
+    }
+
+    /// Returns `U(r)` (`cm^-1`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::PotentialKind;
+    /// let morse = PotentialKind::Morse { de: 1000.0, a: 2.0, re: 1.0 };
+    /// assert_eq!(morse.eval(1.0), 0.0);
+    /// let tabulated = PotentialKind::tabulated(vec![(0.0, 1.0), (1.0, 0.0), (2.0, 1.0)]);
+    /// assert_eq!(tabulated.eval(1.0), 0.0);
+    /// ```
+    pub fn eval(&self, r: f64) -> f64 {
+        match self {
+            PotentialKind::Morse { de, a, re } => de * (1.0 - (-a * (r - re)).exp()).powi(2),
+            PotentialKind::HulburtHirschfelder { de, a, re, b, c } => {
+                let x = a * (r - re);
+                de * ((1.0 - (-x).exp()).powi(2) + c * x.powi(3) * (1.0 + b * x) * (-2.0 * x).exp())
+            }
+            PotentialKind::ExtendedMorse { de, re, p, beta } => {
+                let y = (r.powi(*p) - re.powi(*p)) / (r.powi(*p) + re.powi(*p));
+                let b = beta.iter().rev().fold(0.0, |acc, b| acc * y + b);
+                de * (1.0 - (-b * (r - re)).exp()).powi(2)
+            }
+            PotentialKind::Tabulated { points, m } => spline(points, m, r),
+        }
+    }
+}
+
+/// Returns the Dunham potential coefficients `(a0 (cm^-1), a1, a2)` of
+/// `U = a0ξ^2(1 + a1ξ + a2ξ^2 + ...)` with `ξ = (r-re)/re`
+fn dunham_potential(constants: &SpectroscopicConstants) -> (f64, f64, f64) {
+    let SpectroscopicConstants {
+        we,
+        wexe,
+        be,
+        alpha_e,
+        ..
+    } = *constants;
+    let a0 = we.powi(2) / (4.0 * be);
+    let a1 = -1.0 - alpha_e * we / (6.0 * be.powi(2));
+    let a2 = 5.0 / 4.0 * a1.powi(2) - 2.0 / 3.0 * wexe / be;
+    (a0, a1, a2)
+}
+
+/// Returns the second derivatives of the natural cubic spline through `points` by the
+/// tridiagonal system with `m0 = mn = 0`
+fn second_derivatives(points: &[(f64, f64)]) -> Vec<f64> {
+    let n = points.len();
+    let (x, y): (Vec<f64>, Vec<f64>) = points.iter().copied().unzip();
+    let mut m = vec![0.0; n];
+    if n < 3 {
+        return m;
+    }
+    let mut c = vec![0.0; n];
+    for i in 1..n - 1 {
+        let (h0, h1) = (x[i] - x[i - 1], x[i + 1] - x[i]);
+        let d = 6.0 * ((y[i + 1] - y[i]) / h1 - (y[i] - y[i - 1]) / h0);
+        let b = 2.0 * (h0 + h1) - h0 * c[i - 1];
+        c[i] = h1 / b;
+        m[i] = (d - h0 * m[i - 1]) / b;
+    }
+    for i in (1..n - 1).rev() {
+        m[i] -= c[i] * m[i + 1];
+    }
+    m
+}
+
+/// Returns the natural cubic spline through `points` with the second derivatives `m` at `r`
+fn spline(points: &[(f64, f64)], m: &[f64], r: f64) -> f64 {
+    let n = points.len();
+    let (x, y): (Vec<f64>, Vec<f64>) = points.iter().copied().unzip();
+    if n < 2 {
+        return y.first().copied().unwrap_or(0.0);
+    }
+    if r < x[0] {
+        // `U = y0 (y0/y1)^((x0-r)/(x1-x0))`
+        if y[0] > y[1] && y[1] > 0.0 {
+            return y[0] * (y[0] / y[1]).powf((x[0] - r) / (x[1] - x[0]));
+        }
+        return y[0] + (y[1] - y[0]) / (x[1] - x[0]) * (r - x[0]);
+    }
+    if r > x[n - 1] {
+        return y[n - 1] + (y[n - 1] - y[n - 2]) / (x[n - 1] - x[n - 2]) * (r - x[n - 1]);
+    }
+
+    let i = x.partition_point(|&v| v <= r).clamp(1, n - 1) - 1;
+    let h = x[i + 1] - x[i];
+    let (a, b) = ((x[i + 1] - r) / h, (r - x[i]) / h);
+    a * y[i] + b * y[i + 1] + ((a.powi(3) - a) * m[i] + (b.powi(3) - b) * m[i + 1]) * h * h / 6.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dunham_potential, PotentialKind};
+    use crate::domain::SpectroscopicConstants;
+
+    #[test]
+    fn tabulated() {
+        // Spline through a Morse potential
+        let morse = PotentialKind::Morse {
+            de: 20000.0,
+            a: 2.5,
+            re: 1.1,
+        };
+        let points: Vec<(f64, f64)> = (0..60)
+            .map(|i| 0.85 + i as f64 * 0.02)
+            .map(|r| (r, morse.eval(r)))
+            .collect();
+        let tabulated = PotentialKind::tabulated(points);
+        // The natural end condition `U'' = 0` is poor within the first interval
+        for r in (0..500).map(|i| 0.87 + i as f64 * 0.002) {
+            let e = morse.eval(r);
+            assert!((tabulated.eval(r) - e).abs() < 1e-3 * e.max(100.0));
+        }
+
+        // Extrapolation
+        assert!(tabulated.eval(0.7) > tabulated.eval(0.8));
+        assert!(tabulated.eval(3.0) > tabulated.eval(2.0));
+    }
+
+    #[test]
+    fn dunham_expansion() {
+        // N₂ X¹Σg+: `U'' = 2a0/re^2`, `U''' = 6a0a1/re^3`, `U'''' = 24a0a2/re^4` at `re`
+        let constants = SpectroscopicConstants {
+            we: 2358.57,
+            wexe: 14.324,
+            be: 1.99824,
+            alpha_e: 0.017318,
+            ..Default::default()
+        };
+        let re: f64 = 1.09768;
+        let (a0, a1, a2) = dunham_potential(&constants);
+        let h = 1e-3;
+        let d = |p: &PotentialKind, n: usize| -> f64 {
+            let u: Vec<f64> = (-2..=2).map(|i| p.eval(re + i as f64 * h)).collect();
+            match n {
+                2 => (u[3] - 2.0 * u[2] + u[1]) / h.powi(2),
+                3 => (u[4] - 2.0 * u[3] + 2.0 * u[1] - u[0]) / (2.0 * h.powi(3)),
+                _ => (u[4] - 4.0 * u[3] + 6.0 * u[2] - 4.0 * u[1] + u[0]) / h.powi(4),
+            }
+        };
+        let close = |a: f64, b: f64| ((a - b) / b).abs() < 1e-3;
+
+        let morse = PotentialKind::morse(&constants, re);
+        assert!(close(d(&morse, 2), 2.0 * a0 / re.powi(2)));
+
+        let hh = PotentialKind::hulburt_hirschfelder(&constants, re, None);
+        assert_eq!(hh.eval(re), 0.0);
+        assert!(close(d(&hh, 2), 2.0 * a0 / re.powi(2)));
+        assert!(close(d(&hh, 3), 6.0 * a0 * a1 / re.powi(3)));
+        assert!(close(d(&hh, 4), 24.0 * a0 * a2 / re.powi(4)));
+
+        let emo = PotentialKind::extended_morse(&constants, re, Some(79845.0));
+        assert_eq!(emo.eval(re), 0.0);
+        assert!(close(d(&emo, 2), 2.0 * a0 / re.powi(2)));
+        assert!(close(d(&emo, 3), 6.0 * a0 * a1 / re.powi(3)));
+        assert!((emo.eval(10.0) - 79845.0).abs() < 1.0);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::domain::Term;
+
+/// Rotational population distribution of a vibrational level
+#[derive(Debug, Clone, Default)]
+pub enum RotationalDistributionKind {
+    /// `gns (2J+1) exp(-F(J)/Tr)/Qr`
+    #[default]
+    Boltzmann,
+    /// Weighted sum `Σ fk gns (2J+1) exp(-F(J)/Tk)/Qr(Tk)` of the Boltzmann distributions
+    /// `[(Tk (eV), fk)]`, e.g. cold and hot populations. The fractions are normalized and
+    /// `Tr` is not used.
+    Components(Vec<(Term, f64)>),
+    /// `[(J, N(J))]`: Relative populations of each level `Fi(J)`, `0` for the levels not in
+    /// the table
+    Table(Vec<(f64, f64)>),
+}
+
+impl RotationalDistributionKind {
+    /// Returns `[(Tk, fk)]`: the Boltzmann components with the fractions summing to `1`,
+    /// empty for `Table`
+    ///
+    /// # Arguments
+    ///
+    /// * `t_r` - `Tr`: Rotational temperature (eV)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{RotationalDistributionKind, Term};
+    /// let kind = RotationalDistributionKind::Components(vec![
+    ///     (Term::new(0.03), 3.0),
+    ///     (Term::new(0.3), 1.0),
+    /// ]);
+    /// let res = kind.components(Term::new(0.1));
+    /// assert_eq!(res.iter().map(|&(_, f)| f).collect::<Vec<_>>(), vec![0.75, 0.25]);
+    /// let res = RotationalDistributionKind::Boltzmann.components(Term::new(0.1));
+    /// assert_eq!((res[0].0.unwrap(), res[0].1), (0.1, 1.0));
+    /// ```
+    pub fn components(&self, t_r: Term) -> Vec<(Term, f64)> {
+        match self {
+            RotationalDistributionKind::Boltzmann => vec![(t_r, 1.0)],
+            RotationalDistributionKind::Components(components) => {
+                let sum: f64 = components.iter().map(|&(_, f)| f).sum();
+                components.iter().map(|&(t, f)| (t, f / sum)).collect()
+            }
+            RotationalDistributionKind::Table(_) => vec![],
+        }
+    }
+}
@@ -0,0 +1,31 @@
+/// Model of the rotational term values of an electronic state
+#[derive(Debug, Clone)]
+pub enum RotationalTermKind {
+    /// `DunhamExpansion` with `J(J+1)`, the same for all the `Fi` levels
+    Dunham,
+    /// `HillVanVleck` for doublet states, with `γ = Calc::r`
+    /// * `a` - `[Ae, αA, ...]`: Spin-orbit coupling constant `Av = Σ a_k (v+1/2)^k`
+    /// * `p` - `p`: Λ-doubling constant of `²Π`
+    /// * `q` - `q`: Λ-doubling constant of `²Π`
+    HillVanVleck { a: Vec<f64>, p: f64, q: f64 },
+    /// `Budo` for triplet states, with `γ = Calc::r`
+    /// * `a` - `[Ae, αA, ...]`: Spin-orbit coupling constant `Av = Σ a_k (v+1/2)^k`
+    /// * `lambda` - `λ`: Spin-spin constant
+    Budo { a: Vec<f64>, lambda: f64 },
+    /// `EffectiveHamiltonian` for any multiplicity, with `Bv` and `Dv` from the Dunham
+    /// parameters and `γ = Calc::r`.
+    /// The Hönl-London factors are computed from the eigenvectors when both states are
+    /// `EffectiveHamiltonian`.
+    /// * `a` - `[Ae, αA, ...]`: Spin-orbit coupling constant `Av = Σ a_k (v+1/2)^k`
+    /// * `a_d` - `AD`: Centrifugal distortion of the spin-orbit coupling constant
+    /// * `lambda` - `λ`: Spin-spin constant
+    /// * `p`, `q`, `o` - Λ-doubling constants of `Π`
+    EffectiveHamiltonian {
+        a: Vec<f64>,
+        a_d: f64,
+        lambda: f64,
+        p: f64,
+        q: f64,
+        o: f64,
+    },
+}
@@ -3,4 +3,5 @@ pub enum SpinQuantumNumberKind {
     Singlet,
     Doublet,
     Triplet,
+    Quartet,
 }
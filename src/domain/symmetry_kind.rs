@@ -0,0 +1,6 @@
+/// Reflection symmetry of a `Σ` state (`Σ+` or `Σ-`)
+#[derive(Debug, Clone)]
+pub enum SymmetryKind {
+    Plus,
+    Minus,
+}
@@ -0,0 +1,76 @@
+/// Electronic transition moment function `Re(r)` (`r` in Å, `Re` in D)
+#[derive(Debug, Clone)]
+pub enum TransitionMomentKind {
+    /// `Re(r) = Σ ci r^i` with the coefficients `[c0, c1, ...]`
+    Polynomial(Vec<f64>),
+    /// Linear interpolation between the points `[(r, Re(r))]` in ascending order of `r`,
+    /// constant outside of the points
+    Tabulated(Vec<(f64, f64)>),
+}
+
+impl TransitionMomentKind {
+    /// Returns `Re(r)` (D)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::TransitionMomentKind;
+    /// let polynomial = TransitionMomentKind::Polynomial(vec![1.0, 2.0]);
+    /// assert_eq!(polynomial.eval(0.5), 2.0);
+    /// let tabulated = TransitionMomentKind::Tabulated(vec![(1.0, 1.0), (2.0, 3.0)]);
+    /// assert_eq!(tabulated.eval(1.5), 2.0);
+    /// assert_eq!(tabulated.eval(3.0), 3.0);
+    /// ```
+    pub fn eval(&self, r: f64) -> f64 {
+        match self {
+            TransitionMomentKind::Polynomial(c) => c.iter().rev().fold(0.0, |acc, c| acc * r + c),
+            TransitionMomentKind::Tabulated(points) => {
+                let i = points.partition_point(|&(x, _)| x <= r);
+                match (i.checked_sub(1).map(|i| points[i]), points.get(i)) {
+                    (Some((x0, y0)), Some(&(x1, y1))) => y0 + (y1 - y0) * (r - x0) / (x1 - x0),
+                    (Some((_, y)), None) | (None, Some(&(_, y))) => y,
+                    (None, None) => 0.0,
+                }
+            }
+        }
+    }
+
+    /// Returns the band strengths `q Re(r-centroid)^2` (D^2) in the form of `Calc::q`
+    /// by the r-centroid approximation
+    ///
+    /// # Arguments
+    ///
+    /// * `bands` - `[(v', v'', q, r-centroid (Å))]`, e.g. `FranckCondon::eval`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::TransitionMomentKind;
+    /// let re = TransitionMomentKind::Polynomial(vec![3.0, -1.0]);
+    /// let res = re.band_strengths(&[(0, 0, 0.5, 1.0), (0, 1, 0.25, 2.0)]);
+    /// assert_eq!(res, vec![(0, 0, 2.0), (0, 1, 0.25)]);
+    /// ```
+    pub fn band_strengths(&self, bands: &[(u8, u8, f64, f64)]) -> Vec<(u8, u8, f64)> {
+        bands
+            .iter()
+            .map(|&(vu, vl, q, r)| (vu, vl, q * self.eval(r).powi(2)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransitionMomentKind;
+
+    #[test]
+    fn tabulated() {
+        let points = vec![(1.0, 2.0), (1.2, 1.0), (1.5, 0.4)];
+        let tabulated = TransitionMomentKind::Tabulated(points.clone());
+        for (r, re) in points {
+            assert_eq!(tabulated.eval(r), re);
+        }
+        assert!((tabulated.eval(1.35) - 0.7).abs() < 1e-12);
+        assert_eq!(tabulated.eval(0.5), 2.0);
+        assert_eq!(TransitionMomentKind::Tabulated(vec![]).eval(1.0), 0.0);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::domain::{constant::E, Term};
+
+/// Vibrational population distribution of an electronic state
+#[derive(Debug, Clone, Default)]
+pub enum VibrationalDistributionKind {
+    /// `exp(-G(v)/Tv)`
+    #[default]
+    Boltzmann,
+    /// `exp(-vE1/Tv - (Ev - vE1)/T)` with `Ev = G(v) - G(0)`, which overpopulates the high
+    /// levels of an anharmonic oscillator when `Tv > T`
+    /// * `t` - `T`: Translational temperature (eV)
+    Treanor { t: Term },
+    /// Relative populations `[(v, N(v))]`, `0` for the levels not in the table
+    Table(Vec<(u8, f64)>),
+}
+
+impl VibrationalDistributionKind {
+    /// Returns the relative population of the vibrational level `v`
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Vibrational level
+    /// * `g` - `G(v)`: Vibrational terms
+    /// * `t_v` - `Tv`: Vibrational temperature (eV)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{Term, VibrationalDistributionKind};
+    /// let g = |v: u8| Term::new(2000.0 * (v as f64 + 0.5) - 10.0 * (v as f64 + 0.5).powi(2));
+    /// let boltzmann = VibrationalDistributionKind::Boltzmann;
+    /// let treanor = VibrationalDistributionKind::Treanor { t: Term::new(0.05) };
+    /// let ratio = |d: &VibrationalDistributionKind| d.eval(10, g, Term::new(0.5)) / d.eval(0, g, Term::new(0.5));
+    /// assert!(ratio(&treanor) > ratio(&boltzmann));
+    /// let table = VibrationalDistributionKind::Table(vec![(0, 1.0), (1, 0.5)]);
+    /// assert_eq!(table.eval(1, g, Term::new(0.5)), 0.5);
+    /// assert_eq!(table.eval(2, g, Term::new(0.5)), 0.0);
+    /// ```
+    pub fn eval(&self, v: u8, g: impl Fn(u8) -> Term, t_v: Term) -> f64 {
+        let kt = |t: Term| E * t.unwrap();
+        match self {
+            VibrationalDistributionKind::Boltzmann => (-g(v).to_jules() / kt(t_v)).exp(),
+            VibrationalDistributionKind::Treanor { t } => {
+                let e_1 = (g(1) - g(0)).to_jules() * v as f64;
+                let e_v = (g(v) - g(0)).to_jules();
+                (-e_1 / kt(t_v) - (e_v - e_1) / kt(*t)).exp()
+            }
+            VibrationalDistributionKind::Table(table) => table
+                .iter()
+                .find(|&&(u, _)| u == v)
+                .map_or(0.0, |&(_, n)| n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VibrationalDistributionKind;
+    use crate::domain::Term;
+
+    #[test]
+    fn treanor() {
+        let g = |v: u8| Term::new(2000.0 * (v as f64 + 0.5) - 12.0 * (v as f64 + 0.5).powi(2));
+        let t_v = Term::new(0.4);
+        let boltzmann = VibrationalDistributionKind::Boltzmann;
+        let ratio = |d: &VibrationalDistributionKind, v| d.eval(v, g, t_v) / d.eval(0, g, t_v);
+
+        // `T = Tv` is the Boltzmann distribution
+        let treanor = VibrationalDistributionKind::Treanor { t: t_v };
+        for v in 0..20 {
+            assert!((ratio(&treanor, v) / ratio(&boltzmann, v) - 1.0).abs() < 1e-12);
+        }
+
+        // `N(1)/N(0)` follows `Tv` and the distribution has a minimum at
+        // `v* = E1 T/(2ωexe Tv) + 1/2`, about 10 here
+        let treanor = VibrationalDistributionKind::Treanor { t: Term::new(0.05) };
+        assert!((ratio(&treanor, 1) / ratio(&boltzmann, 1) - 1.0).abs() < 1e-12);
+        let minimum = (0..30)
+            .min_by(|&a, &b| ratio(&treanor, a).total_cmp(&ratio(&treanor, b)))
+            .unwrap();
+        assert!((9..=11).contains(&minimum));
+        assert!(ratio(&treanor, 25) > ratio(&treanor, minimum));
+    }
+}
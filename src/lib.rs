@@ -15,53 +15,64 @@ mod tests {
         let spin_configs: SpinConfigs = vec![
             (
                 CouplingKind::B,
-                DoubletBranchKind::P1,
+                DoubletBranchKind::P1.into(),
                 (6..21).map(|v| v as f64 + 0.5).collect(),
                 // (1..12).map(|v| v as f64 + 0.5).collect(),
             ),
             (
                 CouplingKind::B,
-                DoubletBranchKind::P2,
+                DoubletBranchKind::P2.into(),
                 (6..21).map(|v| v as f64 + 0.5).collect(),
                 // (3..12).map(|v| v as f64 + 0.5).collect(),
             ),
             (
                 CouplingKind::B,
-                DoubletBranchKind::Q1,
+                DoubletBranchKind::Q1.into(),
                 (2..26).map(|v| v as f64 + 0.5).collect(),
                 // (0..12).map(|v| v as f64 + 0.5).collect(),
             ),
             (
                 CouplingKind::B,
-                DoubletBranchKind::Q2,
+                DoubletBranchKind::Q2.into(),
                 (4..25).map(|v| v as f64 + 0.5).collect(),
                 // (2..12).map(|v| v as f64 + 0.5).collect(),
             ),
             (
                 CouplingKind::B,
-                DoubletBranchKind::R1,
+                DoubletBranchKind::R1.into(),
                 (1..26).map(|v| v as f64 + 0.5).collect(),
                 // (1..12).map(|v| v as f64 + 0.5).collect(),
             ),
             (
                 CouplingKind::B,
-                DoubletBranchKind::R2,
+                DoubletBranchKind::R2.into(),
                 (3..26).map(|v| v as f64 + 0.5).collect(),
                 // (1..12).map(|v| v as f64 + 0.5).collect(),
             ),
         ];
 
-        let dunham_expression_params_u = vec![
-            vec![0.0, 1153.3, -19.48, -0.4],
-            vec![1.320, -0.022],
-            vec![-4.0e-6],
-        ];
+        let dunham_expression_params_u = SpectroscopicConstants {
+            we: 1153.3,
+            wexe: 19.48,
+            weye: -0.4,
+            be: 1.320,
+            alpha_e: 0.022,
+            de: 4.0e-6,
+            ..Default::default()
+        }
+        .into();
 
-        let dunham_expression_params_l = vec![
-            vec![0.0, 1308.1, -11.10, 0.093],
-            vec![1.4172, -0.0184, 0.00011],
-            vec![-6.5e-6],
-        ];
+        let dunham_expression_params_l = SpectroscopicConstants {
+            we: 1308.1,
+            wexe: 11.10,
+            weye: 0.093,
+            be: 1.4172,
+            alpha_e: 0.0184,
+            gamma_e: 0.00011,
+            de: 6.5e-6,
+            ..Default::default()
+        }
+        .into();
 
         let q: Vec<(u8, u8, f64)> = vec![
             (0, 0, 0.6765),
@@ -72,10 +83,7 @@ mod tests {
             (2, 3, 0.3935),
         ];
 
-        let wave_lengths: Vec<f64> = (0..20000)
-            .into_iter()
-            .map(|v| (v as f64) * 0.1e-11 + 200e-9)
-            .collect();
+        let wave_lengths: Vec<f64> = (0..20000).map(|v| (v as f64) * 0.1e-11 + 200e-9).collect();
         // let wave_lengths: Vec<f64> = (0..20000)
         //     .into_iter()
         //     .map(|v| (v as f64) * 0.1e-12 + 202e-9)
@@ -84,6 +92,20 @@ mod tests {
         let c = Calc {
             dunham_expression_params_u,
             dunham_expression_params_l,
+            rotational_term_kind_u: RotationalTermKind::HillVanVleck {
+                a: vec![-1.1],
+                p: 0.0,
+                q: 0.0,
+            },
+            rotational_term_kind_l: RotationalTermKind::HillVanVleck {
+                a: vec![28.1],
+                p: 0.0337,
+                q: 0.0388,
+            },
+            symmetry_kind_u: SymmetryKind::Plus,
+            symmetry_kind_l: SymmetryKind::Plus,
+            nuclear_spin_kind_u: NuclearSpinKind::Heteronuclear,
+            nuclear_spin_kind_l: NuclearSpinKind::Heteronuclear,
             fwhm: 5.0e-10,
             lu: 2.0,
             ll: 1.0,
@@ -94,14 +116,17 @@ mod tests {
             t_e_u: Term::new(49399.6),
             t_e_l: Term::new(0.0),
             t_r: Term::new(0.1189),
+            rotational_distribution_kind: RotationalDistributionKind::Boltzmann,
             t_v: Term::new(0.2120),
+            vibrational_distribution_kind: VibrationalDistributionKind::Boltzmann,
             wave_lengths: wave_lengths.clone(),
+            n_u: None,
         };
 
         let res: Vec<(f64, f64)> = wave_lengths
             .clone()
             .into_iter()
-            .zip(c.clone().exec().into_iter())
+            .zip(c.clone().exec())
             .collect();
 
         let res = res
@@ -1,7 +1,21 @@
 pub use crate::domain::{
-    CouplingKind, DoubletBranchKind, SingletBranchKind, SpinQuantumNumberKind, Term,
+    AtomicMasses, BornOppenheimerBreakdown, BranchKind, CouplingKind, DoubletBranchKind,
+    DunhamCoefficients, DunhamCoefficientsError, InversionSymmetryKind, NuclearSpinKind,
+    ParityKind, PotentialKind, RotationalDistributionKind, RotationalTermKind, SingletBranchKind,
+    SpectroscopicConstants, SpinQuantumNumberKind, SymmetryKind, Term, TransitionMomentKind,
     TripletBranchKind::{self, *},
+    VibrationalDistributionKind,
 };
 pub use crate::usecase::{
-    calc::*, dunham_expansion::DunhamExpansion, gaussian::Gaussian, honl_london_factor::*,
+    budo::Budo,
+    calc::*,
+    dunham_expansion::DunhamExpansion,
+    effective_hamiltonian::{EffectiveHamiltonian, MolecularConstants, RotationalLevels},
+    electronic_state::{ElectronicState, RotationalLevel},
+    franck_condon::{FranckCondon, Numerov, NumerovError},
+    gaussian::Gaussian,
+    hill_van_vleck::HillVanVleck,
+    honl_london_factor::*,
+    populations::{Population, Populations},
+    rkr::Rkr,
 };
@@ -1,4 +1,12 @@
+pub mod budo;
 pub mod calc;
 pub mod dunham_expansion;
+pub mod effective_hamiltonian;
+pub mod electronic_state;
+pub mod franck_condon;
 pub mod gaussian;
+pub mod hill_van_vleck;
 pub mod honl_london_factor;
+pub mod jacobi;
+pub mod populations;
+pub mod rkr;
@@ -0,0 +1,200 @@
+use crate::prelude::{EffectiveHamiltonian, MolecularConstants, ParityKind, Term};
+
+type Params = Vec<Vec<f64>>;
+
+/// Budó rotational term values of a triplet state
+#[derive(Debug, Clone)]
+pub struct Budo {
+    v: f64,
+    j: f64,
+    l: f64,
+    a: Vec<f64>,
+    lambda: f64,
+    r: f64,
+    params: Params,
+}
+
+impl Budo {
+    /// Returns the new Budó instance
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Vibrational level
+    /// * `j` - Rotational level
+    /// * `l` - `Λ`: Electronic state
+    /// * `a` - `[Ae, αA, ...]`: Spin-orbit coupling constant `Av = Σ a_k (v+1/2)^k`
+    ///   (`cm^-1`)
+    /// * `lambda` - `λ`: Spin-spin constant (`cm^-1`)
+    /// * `r` - `γ`: Spin-rotation constant (`cm^-1`)
+    /// * `params` - Dunham parameters (see `DunhamExpansion`), the vibrational row is ignored
+    pub fn new(v: f64, j: f64, l: f64, a: Vec<f64>, lambda: f64, r: f64, params: Params) -> Self {
+        Self {
+            v,
+            j,
+            l,
+            a,
+            lambda,
+            r,
+            params,
+        }
+    }
+
+    /// Returns the rotational terms `(F1, F2, F3)`, consuming `self` value
+    ///
+    /// `F1`, `F2` and `F3` are the eigenvalues of `EffectiveHamiltonian` for `S = 1` with
+    /// `Bv`, `Av`, `λ` and `γ` (Budó's matrix), divided by `Bv` and substituted into the
+    /// Dunham rotational series in place of `J(J+1)`. For `³Σ` they are Schlapp's
+    /// `F1(J = N+1)`, `F2(J = N)` and `F3(J = N-1)`.
+    /// The levels which do not exist at low `J` are `NaN`; the highest `Fi` are missing
+    /// if `Y = A/B > 2` and the lowest `Fi` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::Budo;
+    /// // ³Σ, `F2 = BJ(J+1) + 2λ/3 - γ`
+    /// let (_, f2, _) = Budo::new(0.0, 3.0, 0.0, vec![], 1.5, 0.01, vec![vec![], vec![2.0]]).eval();
+    /// assert!((f2.unwrap() - 24.99).abs() < 1e-9);
+    /// ```
+    pub fn eval(self) -> (Term, Term, Term) {
+        let v = self.v + 0.5; // v+1/2
+        let y: Vec<f64> = self
+            .params
+            .iter()
+            .map(|vec| {
+                vec.iter()
+                    .enumerate()
+                    .map(|(k, &y)| y * v.powi(k as i32))
+                    .sum()
+            })
+            .collect();
+        let b = y.get(1).copied().unwrap_or(0.0);
+        let a: f64 = self
+            .a
+            .iter()
+            .enumerate()
+            .map(|(k, &a)| a * v.powi(k as i32))
+            .sum();
+
+        let Self {
+            j, l, lambda, r, ..
+        } = self;
+        let constants = MolecularConstants {
+            b,
+            a,
+            r,
+            lambda,
+            ..Default::default()
+        };
+
+        let f = |e: f64| {
+            if b == 0.0 {
+                return Term::new(e);
+            }
+            let u = e / b;
+            let res = y
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(k, &y)| y * u.powi(k as i32))
+                .sum();
+            Term::new(res)
+        };
+
+        // Without the Λ-doubling the parity does not matter
+        let res: Vec<Term> = EffectiveHamiltonian::new(1.0, l, j, constants, ParityKind::E)
+            .eval()
+            .into_iter()
+            .map(|level| level.map_or(Term::new(f64::NAN), |(e, _)| f(e.unwrap())))
+            .collect();
+        (res[0], res[1], res[2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Budo;
+
+    #[test]
+    fn schlapp() {
+        // O₂ X³Σg-
+        let (b, lambda, r) = (1.4377, 1.9848, -0.0084);
+        let params = vec![vec![], vec![b]];
+        for j in (1..30).map(|v| v as f64) {
+            let (f1, f2, f3) = Budo::new(0.0, j, 0.0, vec![], lambda, r, params.clone()).eval();
+            let z = j * (j + 1.0);
+            let e2 = b * z + 2.0 / 3.0 * lambda - r;
+            let e13 = b * (2.0 * z + 2.0) - 3.0 * r - 2.0 / 3.0 * lambda;
+            assert!((f2.unwrap() - e2).abs() < 1e-9);
+            assert!((f1.unwrap() + f3.unwrap() - e13).abs() < 1e-9);
+            assert!(f1.unwrap() < f2.unwrap() && f2.unwrap() < f3.unwrap());
+        }
+
+        // Without λ and γ, `BN(N+1)`
+        for j in (1..30).map(|v| v as f64) {
+            let (f1, f2, f3) = Budo::new(0.0, j, 0.0, vec![], 0.0, 0.0, params.clone()).eval();
+            assert!((f1.unwrap() - b * (j - 1.0) * j).abs() < 1e-9);
+            assert!((f2.unwrap() - b * j * (j + 1.0)).abs() < 1e-9);
+            assert!((f3.unwrap() - b * (j + 1.0) * (j + 2.0)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn budo() {
+        // N₂ B³Πg, Budó's formulas up to the origin `B/3` (Z. Phys. 96, 219 (1935)). They
+        // are approximate and agree with the eigenvalues to 0.06 `cm^-1` at `J = 2` and to
+        // 2e-3 `cm^-1` from `J = 13`, so the exact `λ` and `γ` terms are checked in
+        // `invariants`.
+        let (b, a) = (1.6374, 42.24);
+        let y = a / b;
+        for j in (2..30).map(|v| v as f64) {
+            let (f1, f2, f3) =
+                Budo::new(0.0, j, 1.0, vec![a], 0.0, 0.0, vec![vec![], vec![b]]).eval();
+            let z = j * (j + 1.0);
+            let y1 = y * (y - 4.0) + 4.0 / 3.0;
+            let y2 = y * (y - 1.0) - 4.0 / 9.0 - 2.0 * z;
+            let e1 = b * (z - (y1 + 4.0 * z).sqrt() - 2.0 / 3.0 * y2 / (y1 + 4.0 * z));
+            let e2 = b * (z + 4.0 / 3.0 * y2 / (y1 + 4.0 * z));
+            let e3 = b * (z + (y1 + 4.0 * z).sqrt() - 2.0 / 3.0 * y2 / (y1 + 4.0 * z));
+            let tol = if j < 13.0 { 0.06 } else { 2e-3 };
+            assert!((f1.unwrap() + b / 3.0 - e1).abs() < tol);
+            assert!((f2.unwrap() + b / 3.0 - e2).abs() < tol);
+            assert!((f3.unwrap() + b / 3.0 - e3).abs() < tol);
+        }
+    }
+
+    #[test]
+    fn invariants() {
+        // ³Π with `A`, `λ` and `γ`: `Σ Fi` and `Σ Fi^2` are the traces of `H` and `H^2` with
+        // Budó's matrix elements in the basis `|Ω=0⟩`, `|Ω=1⟩` and `|Ω=2⟩`
+        let (b, a, lambda, r) = (1.6374, 42.24, 1.2, -0.003);
+        for j in (2..30).map(|v| v as f64) {
+            let (f1, f2, f3) =
+                Budo::new(0.0, j, 1.0, vec![a], lambda, r, vec![vec![], vec![b]]).eval();
+            let f = [f1.unwrap(), f2.unwrap(), f3.unwrap()];
+            let z = j * (j + 1.0);
+            let diag = [
+                -a + b * (z + 1.0) - 2.0 * r + 2.0 / 3.0 * lambda,
+                b * (z + 1.0) - 2.0 * r - 4.0 / 3.0 * lambda,
+                a + b * (z - 3.0) + 2.0 / 3.0 * lambda,
+            ];
+            let off = [2.0 * z, 2.0 * (z - 2.0)].map(|x| (r / 2.0 - b).powi(2) * x);
+            let sum = |v: &[f64]| v.iter().sum::<f64>();
+            let sq = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>();
+            assert!((sum(&f) - sum(&diag)).abs() < 1e-9);
+            assert!((sq(&f) - sq(&diag) - 2.0 * sum(&off)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn missing_levels() {
+        let params = vec![vec![], vec![1.0]];
+        let (f1, f2, f3) = Budo::new(0.0, 0.0, 0.0, vec![], 0.0, 0.0, params.clone()).eval();
+        assert!(f1.unwrap().is_nan() && f2.unwrap().is_nan());
+        assert!((f3.unwrap() - 2.0).abs() < 1e-9);
+
+        let (f1, f2, f3) = Budo::new(0.0, 1.0, 1.0, vec![20.0], 0.0, 0.0, params).eval();
+        assert!(f1.unwrap().is_finite() && f2.unwrap().is_finite());
+        assert!(f3.unwrap().is_nan());
+    }
+}
@@ -1,9 +1,11 @@
+use std::f64::consts::PI;
+
 use crate::{
-    domain::constant::{C, E, H},
+    domain::constant::{C, DEBYE, EPSILON_0, H},
     prelude::*,
 };
 
-pub type SpinConfigs = Vec<(CouplingKind, DoubletBranchKind, Vec<f64>)>;
+pub type SpinConfigs = Vec<(CouplingKind, BranchKind, Vec<f64>)>;
 
 #[derive(Debug, Clone)]
 pub struct Calc {
@@ -14,161 +16,1282 @@ pub struct Calc {
     pub lu: f64,
     /// `Λ''`: Electronic state
     pub ll: f64,
-    /// `γ`: Rotation-vibration interaction constant
+    /// `γ`: Spin-rotation constant (`cm^-1`) used by `RotationalTermKind::HillVanVleck`,
+    /// `RotationalTermKind::Budo` and `RotationalTermKind::EffectiveHamiltonian`
     pub r: f64,
     /// * upper level: u8
     /// * lower level: u8
-    /// * `q`: Franck-Condon factor
+    /// * `q`: Franck-Condon factor, or the band strength `|<v'|Re(r)|v''>|^2` for a varying
+    ///   electronic transition moment (see `FranckCondon::band_strengths` and
+    ///   `TransitionMomentKind::band_strengths`)
     pub q: Vec<(u8, u8, f64)>,
-    /// |k\l|0      |1    |2    |3   |4   |
-    /// |---|-------|-----|-----|----|----|
-    /// |0  |       |`Be` |`-De`|`He`|`Le`|
-    /// |1  |`ωe`   |`-αe`|`-βe`|    |    |
-    /// |2  |`-ωexe`|`γe` |     |    |    |
-    /// |3  |`ωeye` |     |     |    |    |
-    /// |4  |`ωeze` |     |     |    |    |
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// vec![
-    ///     vec![0.0, we, -wexe, weye, 0.0],
-    ///     vec![b, -alpha, r],
-    ///     vec![-d, -beta]
-    /// ]
-    /// ```
-    pub dunham_expression_params_u: Vec<Vec<f64>>,
-    pub dunham_expression_params_l: Vec<Vec<f64>>,
+    /// Upper Dunham coefficients `Y_kl`
+    pub dunham_expression_params_u: DunhamCoefficients,
+    /// Lower Dunham coefficients `Y_kl`
+    pub dunham_expression_params_l: DunhamCoefficients,
+    /// Model of the upper rotational term values
+    pub rotational_term_kind_u: RotationalTermKind,
+    /// Model of the lower rotational term values
+    pub rotational_term_kind_l: RotationalTermKind,
+    /// Reflection symmetry of the upper state, used when `Λ' = 0`
+    pub symmetry_kind_u: SymmetryKind,
+    /// Reflection symmetry of the lower state, used when `Λ'' = 0`
+    pub symmetry_kind_l: SymmetryKind,
+    /// Nuclear spin statistics of the upper state
+    pub nuclear_spin_kind_u: NuclearSpinKind,
+    /// Nuclear spin statistics of the lower state
+    pub nuclear_spin_kind_l: NuclearSpinKind,
     /// `Tv`: Vibrational temperature (eV)
     pub t_v: Term,
+    /// Model of the upper vibrational populations
+    pub vibrational_distribution_kind: VibrationalDistributionKind,
     /// `Tr`: Rotational temperature (eV)
     pub t_r: Term,
-    /// `Te`: Upper minimum electronic energy (eV)
+    /// Model of the upper rotational populations
+    pub rotational_distribution_kind: RotationalDistributionKind,
+    /// `Te`: Upper minimum electronic energy (`cm^-1`)
     pub t_e_u: Term,
-    /// `Te`: Lower minimum electronic energy (eV)
+    /// `Te`: Lower minimum electronic energy (`cm^-1`)
     pub t_e_l: Term,
     /// * `fwhm` - full width half maximum (m)
     pub fwhm: f64,
+    /// `N'`: Number density of the upper state (`m^-3`). If given, `exec` returns the
+    /// emission coefficients (W m^-3 sr^-1 nm^-1) with `q` as the band strengths
+    /// `|<v'|Re|v''>|^2` (D^2), and the vibrational and rotational distributions distribute
+    /// `N'` over the upper levels.
+    pub n_u: Option<f64>,
 }
 
+/// A line of `Calc`
+/// * `0` - `v'`: Upper vibrational level
+/// * `1` - `v''`: Lower vibrational level
+/// * `2` - `J''`: Lower rotational level
+/// * `3` - `J'`: Upper rotational level
+/// * `4` - `λ`: Wave length (m)
+/// * `5` - `S`: Line strength
+/// * `6` - `G(v')`: Upper vibrational term
+/// * `7` - `F(J')`: Upper rotational term
+/// * `8` - Parity of the upper level
+type Line = (u8, u8, f64, f64, f64, f64, Term, Term, ParityKind);
+
 impl Calc {
+    /// Returns the spectrum at `wave_lengths`, consuming `self` value
+    ///
+    /// The intensities are in arbitrary units unless `n_u` is given, in which case they
+    /// are the emission coefficients (W m^-3 sr^-1 nm^-1).
     pub fn exec(self) -> Vec<f64> {
-        let res: Vec<f64> = match self.spin_quantum_number_kind {
-            SpinQuantumNumberKind::Singlet => unimplemented!(),
-            SpinQuantumNumberKind::Doublet => {
-                let res: Vec<f64> = self
-                    .q
-                    .into_iter()
-                    .map(|(vl_u, vl_l, q)| {
-                        let e_v_u = DunhamExpansion::new(
-                            vl_u as f64,
-                            0.0,
-                            vec![self.dunham_expression_params_u[0].clone()],
-                        )
-                        .eval();
-                        let e_v_l = DunhamExpansion::new(
-                            vl_l as f64,
-                            0.0,
-                            vec![self.dunham_expression_params_l[0].clone()],
-                        )
-                        .eval();
-
-                        let i_v_u =
-                            q * ((-1.0 * e_v_u.unwrap() * H * C) / (E * self.t_v.unwrap())).exp();
-
-                        println!("{}", i_v_u);
-                        // let i_v_l =
-                        //     q * ((-1.0 * e_v_l.unwrap() * H * C) / (E * self.t_v.unwrap())).exp();
-
-                        let res: Vec<f64> = self
-                            .spin_configs
-                            .clone()
-                            .into_iter()
-                            .map(|(coupling_kind, branch_kind, j)| {
-                                let res: Vec<f64> = j
-                                    .into_iter()
-                                    .map(|j| {
-                                        let mut params_u = self.dunham_expression_params_u.clone();
-                                        params_u[0] = vec![];
-
-                                        let mut params_l = self.dunham_expression_params_l.clone();
-                                        params_l[0] = vec![];
-                                        let e_r_u =
-                                            DunhamExpansion::new(vl_u as f64, j, params_u.clone())
-                                                .eval();
-                                        let e_r_l =
-                                            DunhamExpansion::new(vl_l as f64, j, params_l.clone())
-                                                .eval();
-
-                                        let s = doublet::HonlLondonFactor {
-                                            j,
-                                            lu: self.lu,
-                                            ll: self.ll,
-                                            r: self.r,
-                                            coupling_kind: coupling_kind.clone(),
-                                            branch_kind: branch_kind.clone(),
-                                        };
-                                        let s = s.eval();
-
-                                        let e_u_sum = self.t_e_u + e_v_u + e_r_u;
-                                        let e_l_sum = self.t_e_l + e_v_l + e_r_l;
-                                        let lambda = e_u_sum.to_wave_length(&e_l_sum);
-                                        let mu = C / lambda;
-
-                                        let i_r_u = s
-                                            * ((-1.0 * e_r_u.unwrap() * H * C)
-                                                / (E * self.t_r.unwrap()))
-                                            .exp();
-
-                                        let i = i_v_u * i_r_u * mu.powi(4);
-
-                                        let gauss =
-                                            |x| i * Gaussian::new(lambda, self.fwhm).calc(x);
-
-                                        let res: Vec<f64> = self
-                                            .wave_lengths
-                                            .clone()
-                                            .into_iter()
-                                            .map(gauss)
-                                            .collect();
-
-                                        res
-                                    })
-                                    .reduce(|accum, item| {
-                                        accum
-                                            .into_iter()
-                                            .enumerate()
-                                            .map(|(i, v)| v + item[i])
-                                            .collect()
-                                    })
-                                    .unwrap();
-
-                                res
-                            })
-                            .reduce(|accum, item| {
-                                accum
-                                    .into_iter()
-                                    .enumerate()
-                                    .map(|(i, v)| v + item[i])
-                                    .collect()
-                            })
-                            .unwrap();
-
-                        res
-                    })
-                    .reduce(|accum, item| {
-                        accum
-                            .into_iter()
-                            .enumerate()
-                            .map(|(i, v)| v + item[i])
-                            .collect()
+        let mut res = vec![0.0; self.wave_lengths.len()];
+        let state = self.state(true);
+        let q_v = self.vibrational_partition_function();
+        let populations: Vec<_> = self
+            .q
+            .iter()
+            .map(|&(v, _, _)| {
+                let population =
+                    state.rotational_population(v, self.t_r, &self.rotational_distribution_kind);
+                (v, population)
+            })
+            .collect();
+        for (vl_u, vl_l, _, j_u, lambda, s, _, e_r_u, parity_kind_u) in self.lines() {
+            let q = self.band_strength(vl_u, vl_l);
+            let (_, population) = populations.iter().find(|(v, _)| *v == vl_u).unwrap();
+            // `N(J')/N` of the population and `S/(2J'+1)` of `A(line)`
+            let i_v_u = q * state.vibrational_population(
+                vl_u,
+                self.t_v,
+                &self.vibrational_distribution_kind,
+            );
+            let i_r_u = population(j_u, parity_kind_u, e_r_u) * s / (2.0 * j_u + 1.0);
+
+            let i = match self.n_u {
+                // `N(v', J') A(line) hν / 4π` with the line profile normalized in nm, where
+                // `2J'+1` of `N(v', J')` cancels out that of `A(line)`
+                Some(n_u) => {
+                    let n = n_u / q_v;
+                    let area = self.fwhm * 1.0e9 * (PI / (4.0 * 2.0_f64.ln())).sqrt();
+                    n * i_v_u
+                        * i_r_u
+                        * self.lambda_components()
+                        * self.einstein_a_coefficient(lambda)
+                        * H
+                        * C
+                        / lambda
+                        / (4.0 * PI)
+                        / area
+                }
+                None => i_v_u * i_r_u * (C / lambda).powi(4),
+            };
+
+            let gaussian = Gaussian::new(lambda, self.fwhm);
+            res.iter_mut()
+                .zip(self.wave_lengths.iter())
+                .for_each(|(res, &x)| *res += i * gaussian.calc(x));
+        }
+        res
+    }
+
+    /// Returns `[(v', v'', A)]`: Einstein coefficients of the bands of `q` (`s^-1`)
+    ///
+    /// `A = 16π^3ν^3|Re|^2 / (3ε0hc^3) (2 - δ0,Λ'+Λ'')/(2 - δ0,Λ')` at the band origin, with
+    /// `q` as the band strengths `|<v'|Re|v''>|^2` (D^2).
+    pub fn einstein_a(&self) -> Vec<(u8, u8, f64)> {
+        self.q
+            .iter()
+            .map(|&(vl_u, vl_l, q)| {
+                let e_u = self.t_e_u + self.state(true).vibrational_term(vl_u);
+                let e_l = self.t_e_l + self.state(false).vibrational_term(vl_l);
+                let lambda = e_u.to_wave_length(&e_l);
+                (vl_u, vl_l, self.einstein_a_coefficient(lambda) * q)
+            })
+            .collect()
+    }
+
+    /// Returns `[(v', v'', J', J'', λ (m), A)]`: Einstein coefficients of the lines (`s^-1`)
+    ///
+    /// `A(line) = A S/(2J'+1)` with `A` at the wave length of the line and the Hönl-London
+    /// factors `S` normalized as `Σ S = 2J'+1` over the lines from each Λ-component of a
+    /// level, as in the Whiting-Nicholls normalization.
+    pub fn einstein_a_lines(&self) -> Vec<(u8, u8, f64, f64, f64, f64)> {
+        self.lines()
+            .into_iter()
+            .map(|(vl_u, vl_l, j, j_u, lambda, s, _, _, _)| {
+                let a = self.einstein_a_coefficient(lambda) * self.band_strength(vl_u, vl_l);
+                let s = s * self.lambda_components();
+                (vl_u, vl_l, j_u, j, lambda, a * s / (2.0 * j_u + 1.0))
+            })
+            .collect()
+    }
+
+    /// Returns `[(v', τ)]`: Radiative lifetimes (s) of the upper vibrational levels with
+    /// `1/τ = Σ A(v', v'')` over the bands of `q`
+    pub fn lifetimes(&self) -> Vec<(u8, f64)> {
+        let mut res: Vec<(u8, f64)> = vec![];
+        for (vl_u, _, a) in self.einstein_a() {
+            match res.iter_mut().find(|(v, _)| *v == vl_u) {
+                Some((_, sum)) => *sum += a,
+                None => res.push((vl_u, a)),
+            }
+        }
+        res.sort_by_key(|&(v, _)| v);
+        res.into_iter().map(|(v, a)| (v, 1.0 / a)).collect()
+    }
+
+    /// Returns `[(v', J', τ)]`: Radiative lifetimes (s) of the upper rotational levels
+    ///
+    /// `1/τ = Σ (Σ A(line) / Σ S/(2J'+1))` over the lines from `J'` in each band of `q`,
+    /// which follows `ν^3` of the lines without all the branches in `spin_configs`.
+    /// The bands without the lines from `J'` are not counted.
+    pub fn lifetimes_j(&self) -> Vec<(u8, f64, f64)> {
+        // `(v', v'', J', Σ A(line), Σ S/(2J'+1))`
+        let mut bands: Vec<(u8, u8, f64, f64, f64)> = vec![];
+        for (vl_u, vl_l, _, j_u, lambda, s, _, _, _) in self.lines() {
+            let w = s / (2.0 * j_u + 1.0);
+            let a = self.einstein_a_coefficient(lambda) * self.band_strength(vl_u, vl_l) * w;
+            match bands
+                .iter_mut()
+                .find(|b| (b.0, b.1, b.2) == (vl_u, vl_l, j_u))
+            {
+                Some(b) => {
+                    b.3 += a;
+                    b.4 += w;
+                }
+                None => bands.push((vl_u, vl_l, j_u, a, w)),
+            }
+        }
+
+        let mut res: Vec<(u8, f64, f64)> = vec![];
+        for (vl_u, _, j_u, a, w) in bands.into_iter().filter(|b| b.4 > 0.0) {
+            match res.iter_mut().find(|r| (r.0, r.1) == (vl_u, j_u)) {
+                Some(r) => r.2 += a / w,
+                None => res.push((vl_u, j_u, a / w)),
+            }
+        }
+        res.sort_by(|a, b| (a.0, a.1).partial_cmp(&(b.0, b.1)).unwrap());
+        res.into_iter().map(|(v, j, a)| (v, j, 1.0 / a)).collect()
+    }
+
+    /// Returns the lines of all the bands of `q` and `spin_configs`
+    ///
+    /// The lines from the levels of `J' < |Ω'|`, including `J' < 0` of some branches at the
+    /// lowest `J''`, and those of `S <= 0` or non-finite `S` are left out, so that
+    /// `S/(2J'+1)` is defined.
+    fn lines(&self) -> Vec<Line> {
+        let j_min = self.state(true).j_min();
+        self.q
+            .iter()
+            .flat_map(|&(vl_u, vl_l, _)| {
+                let e_v_u = self.state(true).vibrational_term(vl_u);
+                let e_v_l = self.state(false).vibrational_term(vl_l);
+                self.spin_configs
+                    .iter()
+                    .flat_map(move |(coupling_kind, branch_kind, j)| {
+                        j.iter().flat_map(move |&j| {
+                            let s = self.honl_london_factor(
+                                j,
+                                coupling_kind.clone(),
+                                branch_kind.clone(),
+                            );
+                            self.rotational_terms(vl_u, vl_l, j, branch_kind, s)
+                                .into_iter()
+                                .map(move |(s, parity_kind_u, e_r_u, e_r_l)| {
+                                    let e_u_sum = self.t_e_u + e_v_u + e_r_u;
+                                    let e_l_sum = self.t_e_l + e_v_l + e_r_l;
+                                    let lambda = e_u_sum.to_wave_length(&e_l_sum);
+                                    let j_u = j + branch_kind.delta_j();
+                                    (vl_u, vl_l, j, j_u, lambda, s, e_v_u, e_r_u, parity_kind_u)
+                                })
+                        })
                     })
-                    .unwrap();
-                res
+            })
+            .filter(|&(_, _, _, j_u, _, s, _, _, _)| j_u >= j_min && s.is_finite() && s > 0.0)
+            .collect()
+    }
+
+    /// Returns `A/|Re|^2` (`s^-1 D^-2`) at the wave length `lambda` (m)
+    fn einstein_a_coefficient(&self, lambda: f64) -> f64 {
+        let nu = C / lambda;
+        let d = |l: f64| if l == 0.0 { 1.0 } else { 2.0 };
+        16.0 * PI.powi(3) * nu.powi(3) * DEBYE.powi(2) / (3.0 * EPSILON_0 * H * C.powi(3))
+            * d(self.lu + self.ll)
+            / d(self.lu)
+    }
+
+    /// Returns the factor of the line strengths which makes `Σ S = 2J'+1` over the lines
+    /// from each Λ-component of the upper level, as in the Whiting-Nicholls normalization
+    ///
+    /// The Hönl-London factors sum to `2J''+1` from each lower level. From `Λ' > 0` to
+    /// `Λ'' = 0`, the lines from either Λ-component have only half of the sum.
+    fn lambda_components(&self) -> f64 {
+        let d = |l: f64| if l == 0.0 { 1.0 } else { 2.0 };
+        f64::max(d(self.lu) / d(self.ll), 1.0)
+    }
+
+    /// Returns `q` of the band `(v', v'')`
+    fn band_strength(&self, vl_u: u8, vl_l: u8) -> f64 {
+        self.q
+            .iter()
+            .find(|&&(u, l, _)| (u, l) == (vl_u, vl_l))
+            .map_or(0.0, |&(_, _, q)| q)
+    }
+
+    /// Returns `Qv = Σ N(v')` over the bound upper vibrational levels, whichever bands `q`
+    /// lists
+    fn vibrational_partition_function(&self) -> f64 {
+        let state = self.state(true);
+        state
+            .vibrational_levels(self.t_v, &self.vibrational_distribution_kind)
+            .into_iter()
+            .map(|v| state.vibrational_population(v, self.t_v, &self.vibrational_distribution_kind))
+            .sum()
+    }
+
+    /// Returns `Qr = Σ gns (2J'+1) exp(-F(J')/Tr)` over the existing levels `Fi(J')` of
+    /// both parities in the upper vibrational level `v`
+    ///
+    /// The sum runs over `J'` until the terms become negligible, with `F(J')` of
+    /// `rotational_term_kind_u`. With `RotationalDistributionKind::Boltzmann`, `exec` divides
+    /// the line intensities by `Qr` of their upper vibrational level, so that the bands of
+    /// different `Bv'` are comparable.
+    pub fn rotational_partition_function(&self, v: u8) -> f64 {
+        self.state(true).rotational_partition_function(v, self.t_r)
+    }
+
+    /// Returns the line strength, the parity of the upper level and the rotational terms of
+    /// the upper and the lower levels of the line from `J''` = `j`, for each parity
+    /// component allowed by the selection rules and the nuclear spin statistics.
+    /// The line strength is `s` unless both states are
+    /// `RotationalTermKind::EffectiveHamiltonian`, in which case it is computed from the
+    /// eigenvectors of the levels.
+    fn rotational_terms(
+        &self,
+        vl_u: u8,
+        vl_l: u8,
+        j: f64,
+        branch_kind: &BranchKind,
+        s: f64,
+    ) -> Vec<(f64, ParityKind, Term, Term)> {
+        let (i, k) = branch_kind.levels();
+        let dj = branch_kind.delta_j();
+        let (state_u, state_l) = (self.state(true), self.state(false));
+        let parity_kinds_u = state_u.parity_kinds(j + dj, i);
+        let parity_kinds_l = state_l.parity_kinds(j, k);
+
+        parity_kinds_u
+            .iter()
+            .flat_map(|u| parity_kinds_l.iter().map(move |l| (u, l)))
+            .filter(|(u, l)| u.is_allowed(l, dj))
+            // Levels missing for the nuclear spin statistics
+            .filter(|(&u, &l)| {
+                state_u.nuclear_spin_kind.weight(j + dj, u) > 0.0
+                    && state_l.nuclear_spin_kind.weight(j, l) > 0.0
+            })
+            .map(|(&u, &l)| {
+                match (
+                    state_u.effective_hamiltonian(vl_u, j + dj, u),
+                    state_l.effective_hamiltonian(vl_l, j, l),
+                ) {
+                    (Some(levels_u), Some(levels_l)) => {
+                        match (levels_u.get(i - 1), levels_l.get(k - 1)) {
+                            (Some(Some((e_u, cu))), Some(Some((e_l, cl)))) => {
+                                let s = general::line_strength(
+                                    j,
+                                    state_u.spin(),
+                                    self.lu,
+                                    self.ll,
+                                    dj,
+                                    cu,
+                                    cl,
+                                );
+                                (s, u, *e_u, *e_l)
+                            }
+                            _ => (0.0, u, Term::new(f64::NAN), Term::new(f64::NAN)),
+                        }
+                    }
+                    _ => (
+                        s,
+                        u,
+                        state_u.rotational_term(vl_u, j + dj, i, u),
+                        state_l.rotational_term(vl_l, j, k, l),
+                    ),
+                }
+            })
+            // Levels which do not exist at low `J`
+            .filter(|(_, _, u, l)| u.unwrap().is_finite() && l.unwrap().is_finite())
+            .collect()
+    }
+
+    /// Returns the upper (`upper = true`) or the lower electronic state
+    fn state(&self, upper: bool) -> ElectronicState {
+        let (l, symmetry_kind, dunham_coefficients, rotational_term_kind, t_e, nuclear_spin_kind) =
+            if upper {
+                (
+                    self.lu,
+                    &self.symmetry_kind_u,
+                    &self.dunham_expression_params_u,
+                    &self.rotational_term_kind_u,
+                    self.t_e_u,
+                    &self.nuclear_spin_kind_u,
+                )
+            } else {
+                (
+                    self.ll,
+                    &self.symmetry_kind_l,
+                    &self.dunham_expression_params_l,
+                    &self.rotational_term_kind_l,
+                    self.t_e_l,
+                    &self.nuclear_spin_kind_l,
+                )
+            };
+        ElectronicState {
+            spin_quantum_number_kind: self.spin_quantum_number_kind.clone(),
+            l,
+            r: self.r,
+            symmetry_kind: symmetry_kind.clone(),
+            dunham_coefficients: dunham_coefficients.clone(),
+            rotational_term_kind: rotational_term_kind.clone(),
+            t_e,
+            g: 1.0,
+            nuclear_spin_kind: nuclear_spin_kind.clone(),
+        }
+    }
+
+    /// Returns the Hönl-London factor of the line from `J''` = `j`
+    fn honl_london_factor(
+        &self,
+        j: f64,
+        coupling_kind: CouplingKind,
+        branch_kind: BranchKind,
+    ) -> f64 {
+        match (&self.spin_quantum_number_kind, branch_kind) {
+            (SpinQuantumNumberKind::Singlet, BranchKind::Singlet(branch_kind)) => {
+                singlet::HonlLondonFactor {
+                    j,
+                    lu: self.lu,
+                    ll: self.ll,
+                    r: self.r,
+                    coupling_kind,
+                    branch_kind,
+                }
+                .eval()
+            }
+            (SpinQuantumNumberKind::Doublet, BranchKind::Doublet(branch_kind)) => {
+                doublet::HonlLondonFactor {
+                    j,
+                    lu: self.lu,
+                    ll: self.ll,
+                    r: self.r,
+                    coupling_kind,
+                    branch_kind,
+                }
+                .eval()
+            }
+            (SpinQuantumNumberKind::Triplet, BranchKind::Triplet(branch_kind)) => {
+                triplet::HonlLondonFactor {
+                    j,
+                    lu: self.lu,
+                    ll: self.ll,
+                    r: self.r,
+                    coupling_kind,
+                    branch_kind,
+                }
+                .eval()
             }
-            SpinQuantumNumberKind::Triplet => unimplemented!(),
+            (_, BranchKind::General { i, k, dj }) => general::HonlLondonFactor {
+                j,
+                s: self.state(true).spin(),
+                lu: self.lu,
+                ll: self.ll,
+                coupling_kind,
+                fu: i,
+                fl: k,
+                dj,
+            }
+            .eval(),
+            (spin_quantum_number_kind, branch_kind) => panic!(
+                "{:?} does not belong to a {:?} system",
+                branch_kind, spin_quantum_number_kind
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::{
+        domain::constant::{C, E, H},
+        prelude::*,
+    };
+
+    /// Returns the P and R branches of a singlet system from `J'' < n`
+    fn p_r(n: i32) -> SpinConfigs {
+        vec![
+            (
+                CouplingKind::B,
+                SingletBranchKind::P1.into(),
+                (1..n).map(|v| v as f64).collect(),
+            ),
+            (
+                CouplingKind::B,
+                SingletBranchKind::R1.into(),
+                (0..n).map(|v| v as f64).collect(),
+            ),
+        ]
+    }
+
+    fn constants(we: f64, be: f64) -> DunhamCoefficients {
+        SpectroscopicConstants {
+            we,
+            be,
+            ..Default::default()
+        }
+        .into()
+    }
+
+    /// Returns `¹Σ+ - ¹Σ+` at `10000 cm^-1` with `ωe = 1000`, `B' = 1.8` and `B'' = 2.0`
+    /// (`cm^-1`), which the tests override with the fields they check
+    fn init() -> Calc {
+        Calc {
+            spin_quantum_number_kind: SpinQuantumNumberKind::Singlet,
+            wave_lengths: vec![],
+            spin_configs: p_r(30),
+            lu: 0.0,
+            ll: 0.0,
+            r: 0.0,
+            q: vec![(0, 0, 1.0)],
+            dunham_expression_params_u: constants(1000.0, 1.8),
+            dunham_expression_params_l: constants(1000.0, 2.0),
+            rotational_term_kind_u: RotationalTermKind::Dunham,
+            rotational_term_kind_l: RotationalTermKind::Dunham,
+            symmetry_kind_u: SymmetryKind::Plus,
+            symmetry_kind_l: SymmetryKind::Plus,
+            nuclear_spin_kind_u: NuclearSpinKind::Heteronuclear,
+            nuclear_spin_kind_l: NuclearSpinKind::Heteronuclear,
+            t_v: Term::new(0.5),
+            vibrational_distribution_kind: VibrationalDistributionKind::Boltzmann,
+            t_r: Term::new(0.03),
+            rotational_distribution_kind: RotationalDistributionKind::Boltzmann,
+            t_e_u: Term::new(10000.0),
+            t_e_l: Term::new(0.0),
+            fwhm: 5.0e-11,
+            n_u: None,
+        }
+    }
+
+    #[test]
+    fn singlet_line_positions_and_branch_ratios() {
+        // CH⁺ A¹Π - X¹Σ+ (0, 0)
+        let line = |branch_kind: SingletBranchKind, j: f64, wave_lengths: Vec<f64>| {
+            let c = Calc {
+                dunham_expression_params_u: SpectroscopicConstants {
+                    we: 1864.4,
+                    wexe: 113.8,
+                    be: 11.42,
+                    alpha_e: 0.99,
+                    ..Default::default()
+                }
+                .into(),
+                dunham_expression_params_l: SpectroscopicConstants {
+                    we: 2857.6,
+                    wexe: 59.3,
+                    be: 14.177,
+                    alpha_e: 0.492,
+                    ..Default::default()
+                }
+                .into(),
+                lu: 1.0,
+                q: vec![(0, 0, 0.9)],
+                spin_configs: vec![(CouplingKind::B, branch_kind.into(), vec![j])],
+                t_e_u: Term::new(24111.0),
+                t_r: Term::new(0.05),
+                wave_lengths: wave_lengths.clone(),
+                ..init()
+            };
+            let res = c.exec();
+            let i = (0..res.len())
+                .max_by(|&a, &b| res[a].total_cmp(&res[b]))
+                .unwrap();
+            (wave_lengths[i], res.iter().sum::<f64>())
         };
 
-        res
+        // The interstellar R(0) line at 4232.548 Å in air, 4233.74 Å in vacuum, to the
+        // accuracy of the constants
+        let (lambda, _) = line(
+            SingletBranchKind::R1,
+            0.0,
+            (0..8000).map(|v| (v as f64) * 1.0e-13 + 423.0e-9).collect(),
+        );
+        assert!((lambda - 423.374e-9).abs() < 0.1e-9);
+
+        // R : Q : P = (J'+1) : (2J'+1) : J' from `J' = 5` (Herzberg), once the `ν^4` of the
+        // lines is divided out
+        let wave_lengths: Vec<f64> = (0..10000)
+            .map(|v| (v as f64) * 1.0e-12 + 420.0e-9)
+            .collect();
+        let (lambda_r, r) = line(SingletBranchKind::R1, 4.0, wave_lengths.clone());
+        let (lambda_q, q) = line(SingletBranchKind::Q1, 5.0, wave_lengths.clone());
+        let (lambda_p, p) = line(SingletBranchKind::P1, 6.0, wave_lengths);
+        let (r, q) = (
+            r * (lambda_r / lambda_p).powi(4),
+            q * (lambda_q / lambda_p).powi(4),
+        );
+        assert!((r / p - 6.0 / 5.0).abs() < 1e-4);
+        assert!((q / p - 11.0 / 5.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn triplet_band_head() {
+        // N₂ C³Πu - B³Πg (0, 0) with the `Fi` of `Dunham` coinciding
+        let coupling_kind = CouplingKind::Intermediate { yu: 21.5, yl: 25.9 };
+        let spin_configs: SpinConfigs = [P1, Q1, R1, P2, Q2, R2, P3, Q3, R3]
+            .into_iter()
+            .map(|b| {
+                (
+                    coupling_kind.clone(),
+                    b.into(),
+                    (2..40).map(|v| v as f64).collect(),
+                )
+            })
+            .collect();
+
+        let wave_lengths: Vec<f64> = (0..2000).map(|v| (v as f64) * 5.0e-12 + 332e-9).collect();
+
+        let c = Calc {
+            dunham_expression_params_u: SpectroscopicConstants {
+                we: 2047.17,
+                wexe: 28.445,
+                be: 1.8247,
+                alpha_e: 0.01868,
+                ..Default::default()
+            }
+            .into(),
+            dunham_expression_params_l: SpectroscopicConstants {
+                we: 1733.39,
+                wexe: 14.122,
+                be: 1.6374,
+                alpha_e: 0.0179,
+                ..Default::default()
+            }
+            .into(),
+            lu: 1.0,
+            ll: 1.0,
+            q: vec![(0, 0, 0.4527)],
+            spin_configs,
+            spin_quantum_number_kind: SpinQuantumNumberKind::Triplet,
+            t_e_u: Term::new(89136.88),
+            t_e_l: Term::new(59619.35),
+            wave_lengths: wave_lengths.clone(),
+            ..init()
+        };
+
+        let res = c.exec();
+        let i = (0..res.len())
+            .max_by(|&a, &b| res[a].total_cmp(&res[b]))
+            .unwrap();
+        // The head of the P branch at 3371.3 Å in air (Pearse and Gaydon), 3372.3 Å in
+        // vacuum, with no lines to the red of it as `B' > B''`
+        assert!((wave_lengths[i] - 337.226e-9).abs() < 0.05e-9);
+        assert!(res
+            .iter()
+            .zip(wave_lengths.iter())
+            .filter(|(_, &x)| x > wave_lengths[i] + 0.2e-9)
+            .all(|(&v, _)| v < 1e-6 * res[i]));
+    }
+
+    #[test]
+    fn quartet_emission_coefficients() {
+        // O₂⁺ b⁴Σg- - a⁴Πu (Y'' = 43) from `J'' = 1/2`, with all the `Fi' - Fk''` branches
+        // and all of `N'` in `v' = 0`
+        let coupling_kind = CouplingKind::Intermediate { yu: 0.0, yl: 43.0 };
+        let spin_configs: SpinConfigs = (1..=4)
+            .flat_map(|i| (1..=4).flat_map(move |k| [-1.0, 0.0, 1.0].map(|dj| (i, k, dj))))
+            .map(|(i, k, dj)| {
+                (
+                    coupling_kind.clone(),
+                    BranchKind::General { i, k, dj },
+                    (0..40).map(|v| v as f64 + 0.5).collect(),
+                )
+            })
+            .collect();
+
+        let c = Calc {
+            dunham_expression_params_u: SpectroscopicConstants {
+                we: 1196.77,
+                wexe: 17.09,
+                be: 1.28729,
+                alpha_e: 0.022,
+                ..Default::default()
+            }
+            .into(),
+            dunham_expression_params_l: SpectroscopicConstants {
+                we: 1035.69,
+                wexe: 10.39,
+                be: 1.10466,
+                alpha_e: 0.01575,
+                ..Default::default()
+            }
+            .into(),
+            symmetry_kind_u: SymmetryKind::Minus,
+            ll: 1.0,
+            spin_configs,
+            spin_quantum_number_kind: SpinQuantumNumberKind::Quartet,
+            t_e_u: Term::new(49552.0),
+            t_e_l: Term::new(32964.0),
+            vibrational_distribution_kind: VibrationalDistributionKind::Table(vec![(0, 1.0)]),
+            t_r: Term::new(0.005),
+            wave_lengths: (0..4000).map(|v| (v as f64) * 5.0e-12 + 590.0e-9).collect(),
+            n_u: Some(1.0e18),
+            ..init()
+        };
+
+        // `Σ A(line) = A` from each of the four levels `Fi'(J')`
+        let a = c.einstein_a()[0].2;
+        let sum: f64 = c
+            .einstein_a_lines()
+            .into_iter()
+            .filter(|&(_, _, j_u, _, _, _)| j_u == 5.5)
+            .map(|(_, _, _, _, _, a)| a)
+            .sum();
+        assert!((sum / a - 4.0).abs() < 1e-2);
+
+        // `∫ε dλ = N' A hν / 4π` at a low `Tr`, where `ν` of the lines hardly varies from
+        // the band origin `Te' - Te'' + G'(0) - G''(0)`
+        let nu = 49552.0 - 32964.0 + (1196.77 / 2.0 - 17.09 / 4.0) - (1035.69 / 2.0 - 10.39 / 4.0);
+        let e = 1.0e18 * a * H * C * nu * 100.0 / (4.0 * PI);
+        let sum: f64 = c.exec().iter().sum::<f64>() * 5.0e-3;
+        assert!((sum / e - 1.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn budo_line_positions() {
+        // N₂ C³Πu - B³Πg (0, 0) R1, R2 and R3 from `J'' = 15` at Budó's formulas up to the
+        // origin `B/3` (Z. Phys. 96, 219 (1935)), which are within 2e-3 `cm^-1` there
+        let budo = |b: f64, a: f64, j: f64| {
+            let (y, z) = (a / b, j * (j + 1.0));
+            let y1 = y * (y - 4.0) + 4.0 / 3.0 + 4.0 * z;
+            let y2 = y * (y - 1.0) - 4.0 / 9.0 - 2.0 * z;
+            [
+                b * (z - y1.sqrt() - 2.0 / 3.0 * y2 / y1),
+                b * (z + 4.0 / 3.0 * y2 / y1),
+                b * (z + y1.sqrt() - 2.0 / 3.0 * y2 / y1),
+            ]
+            .map(|e| e - b / 3.0)
+        };
+        let (bu, bl) = (1.8247 - 0.01868 / 2.0, 1.6374 - 0.0179 / 2.0);
+        let (fu, fl) = (budo(bu, 39.2, 16.0), budo(bl, 42.24, 15.0));
+        let nu =
+            89136.88 - 59619.35 + (2047.17 / 2.0 - 28.445 / 4.0) - (1733.39 / 2.0 - 14.122 / 4.0);
+
+        for (i, branch_kind) in [R1, R2, R3].into_iter().enumerate() {
+            let lambda = 1.0e-2 / (nu + fu[i] - fl[i]);
+            let wave_lengths: Vec<f64> = (0..200)
+                .map(|v| (v as f64 - 100.0) * 2.0e-14 + lambda)
+                .collect();
+            let c = Calc {
+                dunham_expression_params_u: SpectroscopicConstants {
+                    we: 2047.17,
+                    wexe: 28.445,
+                    be: 1.8247,
+                    alpha_e: 0.01868,
+                    ..Default::default()
+                }
+                .into(),
+                dunham_expression_params_l: SpectroscopicConstants {
+                    we: 1733.39,
+                    wexe: 14.122,
+                    be: 1.6374,
+                    alpha_e: 0.0179,
+                    ..Default::default()
+                }
+                .into(),
+                rotational_term_kind_u: RotationalTermKind::Budo {
+                    a: vec![39.2],
+                    lambda: 0.0,
+                },
+                rotational_term_kind_l: RotationalTermKind::Budo {
+                    a: vec![42.24],
+                    lambda: 0.0,
+                },
+                fwhm: 1.0e-13,
+                lu: 1.0,
+                ll: 1.0,
+                q: vec![(0, 0, 0.4527)],
+                spin_configs: vec![(
+                    CouplingKind::Intermediate { yu: 21.5, yl: 25.9 },
+                    branch_kind.into(),
+                    vec![15.0],
+                )],
+                spin_quantum_number_kind: SpinQuantumNumberKind::Triplet,
+                t_e_u: Term::new(89136.88),
+                t_e_l: Term::new(59619.35),
+                wave_lengths: wave_lengths.clone(),
+                ..init()
+            };
+            let res = c.exec();
+            let k = (0..res.len())
+                .max_by(|&a, &b| res[a].total_cmp(&res[b]))
+                .unwrap();
+            assert!((wave_lengths[k] - lambda).abs() < 1.0e-13);
+        }
+    }
+
+    #[test]
+    fn doublet_levels_split_by_gamma() {
+        // CN B²Σ+ - X²Σ+ R1(10.5) and R2(9.5) share N'' = 10
+        let peak = |r: f64, branch_kind: DoubletBranchKind, j: f64| {
+            let wave_lengths: Vec<f64> =
+                (0..8000).map(|v| (v as f64) * 1.0e-13 + 386.5e-9).collect();
+            let c = Calc {
+                dunham_expression_params_u: SpectroscopicConstants {
+                    we: 2163.9,
+                    wexe: 20.2,
+                    be: 1.9730,
+                    alpha_e: 0.023,
+                    ..Default::default()
+                }
+                .into(),
+                dunham_expression_params_l: SpectroscopicConstants {
+                    we: 2068.6,
+                    wexe: 13.1,
+                    be: 1.8997,
+                    alpha_e: 0.0174,
+                    ..Default::default()
+                }
+                .into(),
+                rotational_term_kind_u: RotationalTermKind::HillVanVleck {
+                    a: vec![],
+                    p: 0.0,
+                    q: 0.0,
+                },
+                rotational_term_kind_l: RotationalTermKind::HillVanVleck {
+                    a: vec![],
+                    p: 0.0,
+                    q: 0.0,
+                },
+                fwhm: 1.0e-13,
+                q: vec![(0, 0, 0.9)],
+                r,
+                spin_configs: vec![(CouplingKind::B, branch_kind.into(), vec![j])],
+                spin_quantum_number_kind: SpinQuantumNumberKind::Doublet,
+                t_e_u: Term::new(25752.0),
+                t_r: Term::new(0.5),
+                wave_lengths: wave_lengths.clone(),
+                ..init()
+            };
+            let res = c.exec();
+            let i = (0..res.len())
+                .max_by(|&a, &b| res[a].total_cmp(&res[b]))
+                .unwrap();
+            wave_lengths[i]
+        };
+
+        assert_eq!(
+            peak(0.0, DoubletBranchKind::R1, 10.5),
+            peak(0.0, DoubletBranchKind::R2, 9.5)
+        );
+        assert_ne!(
+            peak(0.02, DoubletBranchKind::R1, 10.5),
+            peak(0.02, DoubletBranchKind::R2, 9.5)
+        );
+    }
+
+    #[test]
+    fn lambda_doubling_splits_q_lines() {
+        // ²Π-²Π Q1(5.5): `e - f` and `f - e`
+        let peaks = |p: f64| {
+            let wave_lengths: Vec<f64> =
+                (0..4000).map(|v| (v as f64) * 1.0e-13 + 400.7e-9).collect();
+            let c = Calc {
+                dunham_expression_params_u: SpectroscopicConstants {
+                    we: 1800.0,
+                    be: 12.0,
+                    ..Default::default()
+                }
+                .into(),
+                dunham_expression_params_l: SpectroscopicConstants {
+                    we: 2800.0,
+                    be: 14.0,
+                    ..Default::default()
+                }
+                .into(),
+                rotational_term_kind_u: RotationalTermKind::HillVanVleck {
+                    a: vec![10.0],
+                    p: 0.0,
+                    q: 0.0,
+                },
+                rotational_term_kind_l: RotationalTermKind::HillVanVleck {
+                    a: vec![-20.0],
+                    p,
+                    q: 0.0,
+                },
+                fwhm: 1.0e-13,
+                lu: 1.0,
+                ll: 1.0,
+                q: vec![(0, 0, 0.9)],
+                spin_configs: vec![(
+                    CouplingKind::Intermediate { yu: 0.8, yl: -1.4 },
+                    DoubletBranchKind::Q1.into(),
+                    vec![5.5],
+                )],
+                spin_quantum_number_kind: SpinQuantumNumberKind::Doublet,
+                t_e_u: Term::new(25500.0),
+                t_r: Term::new(0.5),
+                wave_lengths,
+                ..init()
+            };
+            let res = c.exec();
+            let max = res.iter().cloned().fold(0.0, f64::max);
+            res.windows(3)
+                .filter(|w| w[1] > w[0] && w[1] >= w[2] && w[1] > max / 2.0)
+                .count()
+        };
+
+        assert_eq!(peaks(0.0), 1);
+        assert_eq!(peaks(0.05), 2);
+    }
+
+    #[test]
+    fn effective_hamiltonian_matches_hill_van_vleck() {
+        // CN B²Σ+ - X²Σ+ with the satellites
+        let spectrum = |rotational_term_kind: RotationalTermKind| {
+            let spin_configs: SpinConfigs = [
+                DoubletBranchKind::P1,
+                DoubletBranchKind::R1,
+                DoubletBranchKind::P2,
+                DoubletBranchKind::R2,
+                DoubletBranchKind::P12,
+                DoubletBranchKind::Q12,
+                DoubletBranchKind::Q21,
+                DoubletBranchKind::R21,
+            ]
+            .into_iter()
+            .map(|b| {
+                (
+                    CouplingKind::B,
+                    b.into(),
+                    (0..30).map(|v| v as f64 + 0.5).collect(),
+                )
+            })
+            .collect();
+            Calc {
+                dunham_expression_params_u: SpectroscopicConstants {
+                    we: 2163.9,
+                    wexe: 20.2,
+                    be: 1.9730,
+                    alpha_e: 0.023,
+                    ..Default::default()
+                }
+                .into(),
+                dunham_expression_params_l: SpectroscopicConstants {
+                    we: 2068.6,
+                    wexe: 13.1,
+                    be: 1.8997,
+                    alpha_e: 0.0174,
+                    ..Default::default()
+                }
+                .into(),
+                rotational_term_kind_u: rotational_term_kind.clone(),
+                rotational_term_kind_l: rotational_term_kind,
+                fwhm: 2.0e-12,
+                q: vec![(0, 0, 0.9)],
+                r: 0.02,
+                spin_configs,
+                spin_quantum_number_kind: SpinQuantumNumberKind::Doublet,
+                t_e_u: Term::new(25752.0),
+                t_r: Term::new(0.5),
+                wave_lengths: (0..4000).map(|v| (v as f64) * 1.0e-12 + 385.0e-9).collect(),
+                ..init()
+            }
+            .exec()
+        };
+
+        let x = spectrum(RotationalTermKind::HillVanVleck {
+            a: vec![],
+            p: 0.0,
+            q: 0.0,
+        });
+        let y = spectrum(RotationalTermKind::EffectiveHamiltonian {
+            a: vec![],
+            a_d: 0.0,
+            lambda: 0.0,
+            p: 0.0,
+            q: 0.0,
+            o: 0.0,
+        });
+        let max = x.iter().cloned().fold(0.0, f64::max);
+        assert!(max > 0.0);
+        assert!(x
+            .iter()
+            .zip(y.iter())
+            .all(|(x, y)| (x - y).abs() < 1e-6 * max));
+    }
+
+    #[test]
+    fn einstein_a_and_emission_coefficients() {
+        // ¹Σ+ - ¹Σ+ at 10000 cm^-1 with `|Re|^2 = 1 D^2`: `A = 3.1362e5 s^-1`
+        let c = Calc {
+            dunham_expression_params_u: constants(1000.0, 0.9),
+            dunham_expression_params_l: constants(1000.0, 1.0),
+            spin_configs: p_r(80),
+            t_r: Term::new(0.005),
+            wave_lengths: (0..12000)
+                .map(|v| (v as f64) * 5.0e-12 + 970.0e-9)
+                .collect(),
+            n_u: Some(1.0e18),
+            ..init()
+        };
+
+        let a = c.einstein_a()[0].2;
+        assert!((a / 3.1362e5 - 1.0).abs() < 1e-4);
+
+        // `Σ A(line) = A` from a level `J'`
+        let sum: f64 = c
+            .einstein_a_lines()
+            .into_iter()
+            .filter(|&(_, _, j_u, _, _, _)| j_u == 5.0)
+            .map(|(_, _, _, _, _, a)| a)
+            .sum();
+        assert!((sum / a - 1.0).abs() < 1e-2);
+
+        // `∫ε dλ = N(v'=0) A hν / 4π` at a low `Tr`, where `ν` of the lines hardly varies,
+        // with `N(v'=0) = N'(1 - exp(-ωe/Tv))` of the harmonic upper state
+        let n_0 = 1.0e18 * (1.0 - (-1000.0 * H * C * 100.0 / (E * 0.5)).exp());
+        let e = n_0 * a * H * C * 1.0e6 / (4.0 * PI);
+        let res = c.exec();
+        let sum: f64 = res.iter().sum::<f64>() * 5.0e-3;
+        assert!((sum / e - 1.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn emission_independent_of_listed_bands() {
+        // `N'` is shared among all the bound upper levels, so the (0, 0) band does not change
+        // when (1, 0) at 11000 cm^-1 is added to `q`
+        let c = Calc {
+            dunham_expression_params_u: constants(1000.0, 0.9),
+            dunham_expression_params_l: constants(1000.0, 1.0),
+            t_r: Term::new(0.005),
+            wave_lengths: (0..12000)
+                .map(|v| (v as f64) * 5.0e-12 + 970.0e-9)
+                .collect(),
+            n_u: Some(1.0e18),
+            ..init()
+        };
+        let both = Calc {
+            q: vec![(0, 0, 1.0), (1, 0, 1.0)],
+            ..c.clone()
+        };
+        let (res, res_both) = (c.exec(), both.exec());
+        assert!(res.iter().any(|&v| v > 0.0));
+        assert!(res
+            .iter()
+            .zip(res_both.iter())
+            .all(|(a, b)| (a - b).abs() <= 1e-12 * a.abs()));
+    }
+
+    #[test]
+    fn emission_coefficients_of_lambda_doublets() {
+        // `∫ε dλ = N(v'=0) A hν / 4π` with both Λ-components of `Λ' > 0` or `Λ'' > 0`
+        let spin_configs: SpinConfigs = [
+            SingletBranchKind::P1,
+            SingletBranchKind::Q1,
+            SingletBranchKind::R1,
+        ]
+        .into_iter()
+        .map(|branch_kind| {
+            let j = (0..80).map(|v| v as f64).collect();
+            (CouplingKind::B, branch_kind.into(), j)
+        })
+        .collect();
+        for (lu, ll) in [(1.0, 0.0), (0.0, 1.0), (1.0, 1.0)] {
+            let c = Calc {
+                lu,
+                ll,
+                dunham_expression_params_u: constants(1000.0, 0.9),
+                dunham_expression_params_l: constants(1000.0, 1.0),
+                spin_configs: spin_configs.clone(),
+                t_r: Term::new(0.005),
+                wave_lengths: (0..12000)
+                    .map(|v| (v as f64) * 5.0e-12 + 970.0e-9)
+                    .collect(),
+                n_u: Some(1.0e18),
+                ..init()
+            };
+            let a = c.einstein_a()[0].2;
+            assert!((c.lifetimes()[0].1 * a - 1.0).abs() < 1e-12);
+
+            let n_0 = 1.0e18 * (1.0 - (-1000.0 * H * C * 100.0 / (E * 0.5)).exp());
+            let e = n_0 * a * H * C * 1.0e6 / (4.0 * PI);
+            let sum: f64 = c.exec().iter().sum::<f64>() * 5.0e-3;
+            assert!((sum / e - 1.0).abs() < 1e-2, "Λ' = {}, Λ'' = {}", lu, ll);
+        }
+    }
+
+    #[test]
+    fn lines_from_existing_levels() {
+        // ¹Π - ¹Σ+ from `J'' = 0`: `J' = 0` of ¹Π and `J' = -1` do not exist
+        let mut spin_configs = p_r(10);
+        spin_configs[0].2.insert(0, 0.0);
+        spin_configs.push((
+            CouplingKind::B,
+            SingletBranchKind::Q1.into(),
+            (0..10).map(|v| v as f64).collect(),
+        ));
+        let c = Calc {
+            lu: 1.0,
+            spin_configs,
+            ..init()
+        };
+
+        let lines = c.einstein_a_lines();
+        assert!(lines
+            .iter()
+            .all(|&(_, _, j_u, _, _, a)| j_u >= 1.0 && a > 0.0));
+        assert_eq!(lines.iter().filter(|l| l.3 == 0.0).count(), 1);
+        assert!(c.exec().iter().all(|v| v.is_finite()));
+
+        // ²Σ+ - ²Σ+: `J' = -1/2` of the P lines from `J'' = 1/2`
+        let spin_configs = [DoubletBranchKind::P1, DoubletBranchKind::P2]
+            .into_iter()
+            .map(|branch_kind| {
+                let j = (0..10).map(|v| v as f64 + 0.5).collect();
+                (CouplingKind::B, branch_kind.into(), j)
+            })
+            .collect();
+        let c = Calc {
+            spin_quantum_number_kind: SpinQuantumNumberKind::Doublet,
+            spin_configs,
+            ..init()
+        };
+        let lines = c.einstein_a_lines();
+        assert!(lines
+            .iter()
+            .all(|&(_, _, j_u, _, _, a)| j_u >= 0.5 && a > 0.0));
+    }
+
+    #[test]
+    fn lifetimes() {
+        let c = Calc {
+            dunham_expression_params_u: constants(1000.0, 0.9),
+            dunham_expression_params_l: constants(1000.0, 1.0),
+            q: vec![(0, 0, 1.0), (0, 1, 0.5), (1, 0, 0.5)],
+            t_r: Term::new(0.005),
+            ..init()
+        };
+
+        let a = c.einstein_a();
+        let res = c.lifetimes();
+        assert_eq!(res.len(), 2);
+        assert!((res[0].1 * (a[0].2 + a[1].2) - 1.0).abs() < 1e-12);
+        assert!((res[1].1 * a[2].2 - 1.0).abs() < 1e-12);
+
+        // `τ(J')` of `v' = 0` is close to `τ` and grows with `J'` as `ν` of the P lines falls
+        let res_j: Vec<(u8, f64, f64)> = c.lifetimes_j().into_iter().filter(|r| r.0 == 0).collect();
+        assert_eq!(res_j[0].1, 0.0);
+        assert!(res_j.iter().all(|r| (r.2 / res[0].1 - 1.0).abs() < 0.05));
+        assert!(res_j[20].2 > res_j[1].2);
+    }
+
+    #[test]
+    fn rotational_partition_function() {
+        // `Qr = kT/hcBv + 1/3` at `kT >> hcBv` and the band intensities independent of `Bv'`
+        let c = Calc {
+            dunham_expression_params_u: SpectroscopicConstants {
+                we: 1000.0,
+                be: 2.0,
+                alpha_e: 0.5,
+                ..Default::default()
+            }
+            .into(),
+            dunham_expression_params_l: constants(1000.0, 2.0),
+            q: vec![(0, 0, 1.0), (1, 0, 1.0)],
+            spin_configs: p_r(60),
+            t_r: Term::new(0.01),
+            t_v: Term::new(1.0e6),
+            wave_lengths: (0..20000)
+                .map(|v| (v as f64) * 1.0e-11 + 860.0e-9)
+                .collect(),
+            ..init()
+        };
+
+        let kt = E * 0.01 / (H * C * 100.0);
+        for v in 0..2 {
+            let bv = 2.0 - 0.5 * (v as f64 + 0.5);
+            let q_r = c.rotational_partition_function(v);
+            assert!((q_r / (kt / bv + 1.0 / 3.0) - 1.0).abs() < 1e-3);
+        }
+
+        // ¹Π: both parities of `J' >= 1`, without `J' = 0` of `F(0) = 0`
+        let pi = Calc {
+            lu: 1.0,
+            ..c.clone()
+        };
+        let q_r = 2.0 * (c.rotational_partition_function(0) - 1.0);
+        assert!((pi.rotational_partition_function(0) / q_r - 1.0).abs() < 1e-12);
+
+        // `ν^4` of the band origins, 11000 and 10000 cm^-1, apart from the spread of the lines
+        let res = c.exec();
+        let (v1, v0) = res.split_at(res.len() / 2);
+        let ratio = v1.iter().sum::<f64>() / v0.iter().sum::<f64>();
+        assert!((ratio / 1.1f64.powi(4) - 1.0).abs() < 2e-2);
+    }
+
+    #[test]
+    fn rotational_distributions() {
+        let spectrum = |rotational_distribution_kind: RotationalDistributionKind| {
+            Calc {
+                fwhm: 2.0e-11,
+                spin_configs: p_r(60),
+                t_r: Term::new(0.02),
+                rotational_distribution_kind,
+                wave_lengths: (0..5000).map(|v| (v as f64) * 1.0e-11 + 980.0e-9).collect(),
+                ..init()
+            }
+            .exec()
+        };
+        let boltzmann = |t: f64| {
+            spectrum(RotationalDistributionKind::Components(vec![(
+                Term::new(t),
+                1.0,
+            )]))
+        };
+        let max = |res: &[f64]| res.iter().cloned().fold(0.0, f64::max);
+
+        // A single component is the Boltzmann distribution at its temperature
+        let (x, y) = (
+            boltzmann(0.02),
+            spectrum(RotationalDistributionKind::Boltzmann),
+        );
+        let y_max = max(&y);
+        assert!(x
+            .iter()
+            .zip(y.iter())
+            .all(|(x, y)| (x - y).abs() < 1e-9 * y_max));
+
+        // Two components are the weighted sum of their spectra
+        let (cold, hot) = (boltzmann(0.01), boltzmann(0.1));
+        let res = spectrum(RotationalDistributionKind::Components(vec![
+            (Term::new(0.01), 3.0),
+            (Term::new(0.1), 1.0),
+        ]));
+        let res_max = max(&res);
+        assert!(res
+            .iter()
+            .zip(cold.iter().zip(hot.iter()))
+            .all(|(res, (cold, hot))| (res - (0.75 * cold + 0.25 * hot)).abs() < 1e-9 * res_max));
+
+        // A table of `(2J'+1) exp(-F(J')/Tr)` is the Boltzmann distribution, and the levels
+        // out of the table do not emit
+        let kt = E * 0.02 / (H * C * 100.0);
+        let table = (0..80)
+            .map(|j| j as f64)
+            .map(|j| (j, (2.0 * j + 1.0) * (-1.8 * j * (j + 1.0) / kt).exp()))
+            .collect();
+        let res = spectrum(RotationalDistributionKind::Table(table));
+        assert!(res
+            .iter()
+            .zip(y.iter())
+            .all(|(x, y)| (x - y).abs() < 1e-6 * y_max));
+        let res = spectrum(RotationalDistributionKind::Table(vec![(5.0, 1.0)]));
+        assert!(max(&res) > 0.0);
+        let peaks = res
+            .windows(3)
+            .filter(|w| w[1] > w[0] && w[1] >= w[2] && w[1] > max(&res) * 1e-3)
+            .count();
+        assert_eq!(peaks, 2);
+    }
+
+    #[test]
+    fn nuclear_spin_statistics() {
+        // ¹Σu+ - ¹Σg+: the upper levels of odd `J'` and the lower levels of even `J''` are
+        // symmetric
+        let calc = |nuclear_spin: Option<f64>, wave_lengths: Vec<f64>| {
+            let nuclear_spin_kind = |inversion_symmetry_kind| match nuclear_spin {
+                Some(i) => NuclearSpinKind::Homonuclear {
+                    i,
+                    inversion_symmetry_kind,
+                },
+                None => NuclearSpinKind::Heteronuclear,
+            };
+            Calc {
+                dunham_expression_params_u: constants(1000.0, 1.83),
+                nuclear_spin_kind_u: nuclear_spin_kind(InversionSymmetryKind::U),
+                nuclear_spin_kind_l: nuclear_spin_kind(InversionSymmetryKind::G),
+                fwhm: 1.0e-14,
+                wave_lengths,
+                ..init()
+            }
+        };
+
+        // `I = 1` (14N2): 2:1 alternation of the lines from odd and even `J'`
+        let lines = calc(None, vec![]).einstein_a_lines();
+        let wave_lengths: Vec<f64> = lines.iter().map(|l| l.4).collect();
+        let x = calc(None, wave_lengths.clone()).exec();
+        let y = calc(Some(1.0), wave_lengths).exec();
+        let ratios: Vec<(f64, f64)> = lines
+            .iter()
+            .zip(x.iter().zip(y.iter()))
+            .map(|(l, (x, y))| (l.2, y / x))
+            .collect();
+        let ratio = |odd: bool| ratios.iter().find(|r| (r.0 % 2.0 == 1.0) == odd).unwrap().1;
+        let (odd, even) = (ratio(true), ratio(false));
+        assert!((odd / even - 2.0).abs() < 1e-6);
+        for (j_u, ratio) in ratios {
+            let expected = if j_u % 2.0 == 1.0 { odd } else { even };
+            assert!((ratio / expected - 1.0).abs() < 1e-6);
+        }
+
+        // `I = 0` (12C2): only the lines from odd `J'` to even `J''`
+        let c = calc(Some(0.0), vec![]);
+        let lines = c.einstein_a_lines();
+        assert_eq!(lines.len(), calc(None, vec![]).einstein_a_lines().len() / 2);
+        assert!(lines.iter().all(|l| l.2 % 2.0 == 1.0 && l.3 % 2.0 == 0.0));
+        let kt = E * 0.03 / (H * C * 100.0);
+        let q_r: f64 = (0..100)
+            .map(|j| j as f64)
+            .filter(|j| j % 2.0 == 1.0)
+            .map(|j| (2.0 * j + 1.0) * (-1.83 * j * (j + 1.0) / kt).exp())
+            .sum();
+        assert!((c.rotational_partition_function(0) / q_r - 1.0).abs() < 1e-9);
     }
 }
@@ -1,4 +1,4 @@
-use crate::prelude::Term;
+use crate::prelude::{DunhamCoefficients, Term};
 
 type Params = Vec<Vec<f64>>;
 
@@ -17,7 +17,7 @@ impl DunhamExpansion {
     ///
     /// * `v` - Vibrational level
     /// * `j` - Rotational level
-    /// * `params` - Dunham parameters
+    /// * `params` - Dunham parameters (see `DunhamCoefficients::to_vec`)
     ///
     /// |k\l|0      |1    |2    |3   |4   |
     /// |---|-------|-----|-----|----|----|
@@ -31,7 +31,7 @@ impl DunhamExpansion {
         Self { v, j, params }
     }
 
-    /// Returns the result of the evaluation, consuming `self` value
+    /// Returns the result of the evaluation `G(v) + Fv(J)`, consuming `self` value
     ///
     /// # Examples
     ///
@@ -43,20 +43,112 @@ impl DunhamExpansion {
     /// }
     /// ```
     pub fn eval(self) -> Term {
-        let v = self.v + 0.5; // v+1/2
+        self.g_v() + self.f_v()
+    }
+
+    /// Returns `G(v) = Σ Y_k0 (v+1/2)^k`: Vibrational term
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::DunhamExpansion;
+    /// let e = DunhamExpansion::new(0.0, 10.0, vec![vec![0.0, 2000.0, -10.0], vec![2.0]]);
+    /// assert_eq!(e.g_v().unwrap(), 997.5);
+    /// ```
+    pub fn g_v(&self) -> Term {
+        Term::new(self.y(0))
+    }
+
+    /// Returns `Bv = Σ Y_k1 (v+1/2)^k`: Effective rotational constant
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::DunhamExpansion;
+    /// let e = DunhamExpansion::new(1.0, 0.0, vec![vec![], vec![2.0, -0.1]]);
+    /// assert!((e.b_v() - 1.85).abs() < 1e-12);
+    /// ```
+    pub fn b_v(&self) -> f64 {
+        self.y(1)
+    }
+
+    /// Returns `Dv = -Σ Y_k2 (v+1/2)^k`: Effective centrifugal distortion constant
+    pub fn d_v(&self) -> f64 {
+        -self.y(2)
+    }
+
+    /// Returns `Hv = Σ Y_k3 (v+1/2)^k`: Effective sextic centrifugal distortion constant
+    pub fn h_v(&self) -> f64 {
+        self.y(3)
+    }
+
+    /// Returns `Fv(J) = Σ_l≥1 Y_l(v) (J(J+1))^l`: Rotational term
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::DunhamExpansion;
+    /// let e = DunhamExpansion::new(0.0, 2.0, vec![vec![0.0, 2000.0], vec![2.0], vec![-0.01]]);
+    /// assert!((e.f_v().unwrap() - (12.0 - 0.36)).abs() < 1e-12);
+    /// ```
+    pub fn f_v(&self) -> Term {
         let j = self.j * (self.j + 1.0); // J(J+1)
-        let res: Params = self
-            .params
-            .iter()
-            .enumerate()
-            .map(|(l, vec)| {
-                vec.iter()
-                    .enumerate()
-                    .map(|(k, &y)| y * v.powi(k as i32) * j.powi(l as i32))
-                    .collect()
+        let res = (1..self.params.len())
+            .map(|l| self.y(l) * j.powi(l as i32))
+            .sum();
+        Term::new(res)
+    }
+
+    /// Returns `Y_l(v) = Σ Y_kl (v+1/2)^k`
+    fn y(&self, l: usize) -> f64 {
+        let v = self.v + 0.5; // v+1/2
+        self.params.get(l).map_or(0.0, |vec| {
+            vec.iter()
+                .enumerate()
+                .map(|(k, &y)| y * v.powi(k as i32))
+                .sum()
+        })
+    }
+
+    /// Returns the table of `G(v)`, `Bv`, `Dv` and `Hv` of the vibrational levels `vs`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{DunhamCoefficients, DunhamExpansion};
+    /// let y = DunhamCoefficients::new()
+    ///     .with(1, 0, 2000.0)
+    ///     .with(0, 1, 2.0)
+    ///     .with(1, 1, -0.1)
+    ///     .with(0, 2, -1.0e-6);
+    /// assert_eq!(
+    ///     DunhamExpansion::table(&y, [0, 1]),
+    ///     "|v|G(v)|Bv|Dv|Hv|\n|---|---|---|---|---|\n|0|1000|1.95|0.000001|0|\n|1|3000|1.85|0.000001|0|"
+    /// );
+    /// ```
+    pub fn table(
+        dunham_coefficients: &DunhamCoefficients,
+        vs: impl IntoIterator<Item = u8>,
+    ) -> String {
+        let params = dunham_coefficients.to_vec();
+        let rows: Vec<String> = vs
+            .into_iter()
+            .map(|v| {
+                let e = DunhamExpansion::new(v as f64, 0.0, params.clone());
+                format!(
+                    "|{}|{}|{}|{}|{}|",
+                    v,
+                    e.g_v().unwrap(),
+                    e.b_v(),
+                    e.d_v(),
+                    e.h_v()
+                )
             })
             .collect();
-        Term::new(res.concat().iter().sum())
+        format!(
+            "|v|G(v)|Bv|Dv|Hv|\n|---|---|---|---|---|\n{}",
+            rows.join("\n")
+        )
     }
 }
 
@@ -83,4 +175,32 @@ mod tests {
         );
         assert_eq!(y.eval().unwrap(), 651.286625);
     }
+
+    #[test]
+    fn vibrational_and_rotational() {
+        let params = vec![
+            vec![0.0, 1308.1, -11.1, 0.093],
+            vec![1.4172, -0.0184, 0.00011],
+            vec![-6.5e-6, -1.0e-7],
+            vec![1.0e-12],
+        ];
+        for v in (0..5).map(|v| v as f64) {
+            let x = v + 0.5;
+            for j in (0..30).map(|j| j as f64) {
+                let e = DunhamExpansion::new(v, j, params.clone());
+                let z = j * (j + 1.0);
+                let g = 1308.1 * x - 11.1 * x.powi(2) + 0.093 * x.powi(3);
+                let b = 1.4172 - 0.0184 * x + 0.00011 * x.powi(2);
+                let d = 6.5e-6 + 1.0e-7 * x;
+                let h = 1.0e-12;
+                assert!((e.g_v().unwrap() - g).abs() < 1e-9);
+                assert!((e.b_v() - b).abs() < 1e-12);
+                assert!((e.d_v() - d).abs() < 1e-15);
+                assert!((e.h_v() - h).abs() < 1e-20);
+                let f = b * z - d * z.powi(2) + h * z.powi(3);
+                assert!((e.f_v().unwrap() - f).abs() < 1e-9);
+                assert!((e.eval().unwrap() - (g + f)).abs() < 1e-9);
+            }
+        }
+    }
 }
@@ -0,0 +1,389 @@
+use crate::{
+    prelude::{ParityKind, Term},
+    usecase::jacobi::Jacobi,
+};
+
+type Matrix = Vec<Vec<f64>>;
+
+/// Term values and Hund's case (a) components of `F1`, ..., `F(2S+1)`
+pub type RotationalLevels = Vec<Option<(Term, Vec<f64>)>>;
+
+/// Molecular constants of a vibrational level (`cm^-1`)
+#[derive(Debug, Clone, Default)]
+pub struct MolecularConstants {
+    /// `Bv`: Rotational constant
+    pub b: f64,
+    /// `Dv`: Centrifugal distortion constant
+    pub d: f64,
+    /// `Av`: Spin-orbit coupling constant
+    pub a: f64,
+    /// `AD`: Centrifugal distortion of the spin-orbit coupling constant
+    pub a_d: f64,
+    /// `γ`: Spin-rotation constant
+    pub r: f64,
+    /// `λ`: Spin-spin constant
+    pub lambda: f64,
+    /// `p`: Λ-doubling constant of `Π`
+    pub p: f64,
+    /// `q`: Λ-doubling constant of `Π`
+    pub q: f64,
+    /// `o`: Λ-doubling constant of `Π`
+    pub o: f64,
+}
+
+/// Effective Hamiltonian of the rotational levels of any multiplicity
+///
+/// `H = B(N²-Lz²) - D(N²-Lz²)² + ALzSz + AD{N²-Lz², LzSz}/2 + γN·S
+/// + (2/3)λ(3Sz²-S²)` and the Λ-doubling of `Π` with `o`, `p` and `q`,
+/// in the Hund's case (a) basis `|Λ S Σ J Ω⟩` (`Σ = -S, ..., S`).
+#[derive(Debug, Clone)]
+pub struct EffectiveHamiltonian {
+    s: f64,
+    l: f64,
+    j: f64,
+    constants: MolecularConstants,
+    parity_kind: ParityKind,
+}
+
+impl EffectiveHamiltonian {
+    /// Returns the new effective Hamiltonian instance
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - `S`: Spin quantum number
+    /// * `l` - `Λ`: Electronic state
+    /// * `j` - Rotational level
+    /// * `constants` - Molecular constants of the vibrational level
+    /// * `parity_kind` - Parity of the levels, only used by the Λ-doubling of `Π`
+    pub fn new(
+        s: f64,
+        l: f64,
+        j: f64,
+        constants: MolecularConstants,
+        parity_kind: ParityKind,
+    ) -> Self {
+        Self {
+            s,
+            l,
+            j,
+            constants,
+            parity_kind,
+        }
+    }
+
+    /// Returns the matrix of the Hamiltonian in the basis `|Σ⟩` of `eval`
+    pub fn matrix(&self) -> Matrix {
+        let Self {
+            s,
+            l,
+            j,
+            constants:
+                MolecularConstants {
+                    b,
+                    d,
+                    a,
+                    a_d,
+                    r,
+                    lambda,
+                    p,
+                    q,
+                    o,
+                },
+            ref parity_kind,
+        } = *self;
+
+        let sigma = self.sigma();
+        let n = sigma.len();
+        let z = j * (j + 1.0);
+        let ss = s * (s + 1.0);
+        // `⟨Ω-1|J+|Ω⟩` and `⟨Σ+1|S+|Σ⟩`
+        let jp = |omega: f64| (z - omega * (omega - 1.0)).max(0.0).sqrt();
+        let sp = |sigma: f64| (ss - sigma * (sigma + 1.0)).max(0.0).sqrt();
+
+        // `N²-Lz²`, `LzSz` and `N·S`
+        let elem = |f: &dyn Fn(f64, f64) -> (f64, f64)| -> Matrix {
+            (0..n)
+                .map(|x| {
+                    (0..n)
+                        .map(|y| {
+                            let (sa, sb) = (sigma[x], sigma[y]);
+                            let (diag, off) = f(sa, l + sa);
+                            if x == y {
+                                diag
+                            } else if sb == sa + 1.0 {
+                                off * jp(l + sb) * sp(sa)
+                            } else if sa == sb + 1.0 {
+                                off * jp(l + sa) * sp(sb)
+                            } else {
+                                0.0
+                            }
+                        })
+                        .collect()
+                })
+                .collect()
+        };
+        let r2 = elem(&|sigma, omega| (z - omega.powi(2) + ss - sigma.powi(2), -1.0));
+        let so = elem(&|sigma, _| (l * sigma, 0.0));
+        let ns = elem(&|sigma, omega| (omega * sigma - ss, 0.5));
+        let mul = |x: &Matrix, y: &Matrix| -> Matrix {
+            (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|k| (0..n).map(|m| x[i][m] * y[m][k]).sum())
+                        .collect()
+                })
+                .collect()
+        };
+        let r4 = mul(&r2, &r2);
+        let (r2so, sor2) = (mul(&r2, &so), mul(&so, &r2));
+
+        // `±(Λ=1, Σa|H|Λ=-1, -Σb)`, the upper sign for `f`
+        let sign = match parity_kind {
+            ParityKind::E => -1.0,
+            ParityKind::F => 1.0,
+        };
+        let lambda_doubling = |sa: f64, sb: f64| {
+            if l != 1.0 {
+                return 0.0;
+            }
+            let (sc, oa) = (-sb, l + sa);
+            let res = if sc == sa {
+                q / 2.0 * jp(oa) * jp(oa - 1.0)
+            } else if sc == sa + 1.0 {
+                (q + p / 2.0) * jp(oa) * sp(sa)
+            } else if sc == sa + 2.0 {
+                (o + p + q) / 2.0 * sp(sa) * sp(sa + 1.0)
+            } else {
+                0.0
+            };
+            sign * res
+        };
+
+        (0..n)
+            .map(|x| {
+                (0..n)
+                    .map(|y| {
+                        let (sa, sb) = (sigma[x], sigma[y]);
+                        let mut res = b * r2[x][y] - d * r4[x][y]
+                            + a * so[x][y]
+                            + a_d / 2.0 * (r2so[x][y] + sor2[x][y])
+                            + r * ns[x][y]
+                            + lambda_doubling(sa, sb);
+                        if x == y {
+                            res += 2.0 / 3.0 * lambda * (3.0 * sa.powi(2) - ss);
+                        }
+                        res
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the term values and the Hund's case (a) components `[Σ=-S, ..., Σ=S]` of
+    /// `F1`, ..., `F(2S+1)`, consuming `self` value
+    ///
+    /// The levels are labelled in ascending order of the energy. The levels which do not
+    /// exist at low `J` are `None`; the highest `Fi` are missing if `Y = A/B > 2` and
+    /// `Λ > 0`, and the lowest `Fi` otherwise. The components can be passed to
+    /// `general::line_strength` for the Hönl-London factors in intermediate coupling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{EffectiveHamiltonian, MolecularConstants, ParityKind};
+    /// // ²Σ, N = 3: `F1 = BN(N+1) + γN/2`
+    /// let constants = MolecularConstants {
+    ///     b: 2.0,
+    ///     r: 0.1,
+    ///     ..Default::default()
+    /// };
+    /// let res = EffectiveHamiltonian::new(0.5, 0.0, 3.5, constants, ParityKind::E).eval();
+    /// let (f1, _) = res[0].clone().unwrap();
+    /// assert!((f1.unwrap() - 24.15).abs() < 1e-9);
+    /// ```
+    pub fn eval(self) -> RotationalLevels {
+        let m = (2.0 * self.s).round() as usize + 1;
+        let sigma = self.sigma();
+        let n = sigma.len();
+        let mut res = vec![None; m];
+        if n == 0 {
+            return res;
+        }
+
+        let MolecularConstants { a, b, .. } = self.constants;
+        let offset = if self.l > 0.0 && b != 0.0 && a / b > 2.0 {
+            0
+        } else {
+            m - n
+        };
+        let k0 = ((sigma[0] + self.s).round()) as usize;
+        for (f, (e, vec)) in Jacobi::new(self.matrix()).eval().into_iter().enumerate() {
+            let mut v = vec![0.0; m];
+            for (k, c) in vec.into_iter().enumerate() {
+                v[k0 + k] = c;
+            }
+            res[f + offset] = Some((Term::new(e), v));
+        }
+        res
+    }
+
+    /// Returns `Σ` of the case (a) basis at `J`
+    fn sigma(&self) -> Vec<f64> {
+        let m = (2.0 * self.s).round() as usize + 1;
+        (0..m)
+            .map(|k| k as f64 - self.s)
+            .filter(|&sigma| (self.l + sigma).abs() <= self.j)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EffectiveHamiltonian, MolecularConstants};
+    use crate::prelude::{Budo, HillVanVleck, ParityKind};
+
+    fn terms(s: f64, l: f64, j: f64, c: MolecularConstants, parity_kind: ParityKind) -> Vec<f64> {
+        EffectiveHamiltonian::new(s, l, j, c, parity_kind)
+            .eval()
+            .into_iter()
+            .map(|v| v.map_or(f64::NAN, |(e, _)| e.unwrap()))
+            .collect()
+    }
+
+    fn assert_eq_term(a: f64, b: f64) {
+        assert!(
+            (a.is_nan() && b.is_nan()) || (a - b).abs() < 1e-9,
+            "{} != {}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn hill_van_vleck() {
+        let (b, a, r, p, q) = (14.19, 28.0, 0.05, 0.0337, 0.0388);
+        for l in [0.0, 1.0, 2.0] {
+            for j in (0..30).map(|v| v as f64 + 0.5).filter(|&j| j >= l - 0.5) {
+                for parity_kind in [ParityKind::E, ParityKind::F] {
+                    let c = MolecularConstants {
+                        b,
+                        a,
+                        r,
+                        p,
+                        q,
+                        ..Default::default()
+                    };
+                    let res = terms(0.5, l, j, c, parity_kind);
+                    let (f1, f2) = HillVanVleck::new(0.0, j, l, vec![a], r, vec![vec![], vec![b]])
+                        .lambda_doubling(p, q, parity_kind)
+                        .eval();
+                    if j > l - 0.5 || l == 0.0 {
+                        assert_eq_term(res[0], f1.unwrap());
+                        assert_eq_term(res[1], f2.unwrap());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn budo() {
+        let (b, a, r, lambda) = (1.6374, 42.24, -0.003, 1.2);
+        for l in [0.0, 1.0] {
+            for j in (0..30).map(|v| v as f64) {
+                let c = MolecularConstants {
+                    b,
+                    a,
+                    r,
+                    lambda,
+                    ..Default::default()
+                };
+                let res = terms(1.0, l, j, c, ParityKind::E);
+                let (f1, f2, f3) =
+                    Budo::new(0.0, j, l, vec![a], lambda, r, vec![vec![], vec![b]]).eval();
+                assert_eq_term(res[0], f1.unwrap());
+                assert_eq_term(res[1], f2.unwrap());
+                assert_eq_term(res[2], f3.unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn centrifugal_distortion() {
+        // `BN(N+1) - D(N(N+1))^2`
+        let (b, d) = (1.9, 6.0e-6);
+        for j in (1..30).map(|v| v as f64) {
+            let c = MolecularConstants {
+                b,
+                d,
+                ..Default::default()
+            };
+            let res = terms(1.0, 0.0, j, c, ParityKind::E);
+            for (f, n) in res.into_iter().zip([j - 1.0, j, j + 1.0]) {
+                let z = n * (n + 1.0);
+                assert!((f - (b * z - d * z * z)).abs() < 1e-9);
+            }
+        }
+
+        // `AD` as `A + AD J(J+1)` in case (a) limit
+        let j = 10.5;
+        let c = |a, a_d| MolecularConstants {
+            b: 1.0,
+            a,
+            a_d,
+            ..Default::default()
+        };
+        let x = terms(0.5, 1.0, j, c(1.0e4, 0.01), ParityKind::E);
+        let y = terms(0.5, 1.0, j, c(1.0e4, 0.0), ParityKind::E);
+        let split = |v: Vec<f64>| v[1] - v[0];
+        let k = (split(x) - split(y)) / (j * (j + 1.0));
+        assert!((k - 0.01).abs() < 1e-3);
+    }
+
+    #[test]
+    fn lambda_doubling() {
+        // ³Π0 in case (a) splits by `2(o+p+q)`, `e` below `f`
+        let (o, p, q) = (0.5, 0.01, 0.001);
+        let c = MolecularConstants {
+            b: 1.0,
+            a: 1.0e4,
+            o,
+            p,
+            q,
+            ..Default::default()
+        };
+        let e = terms(1.0, 1.0, 5.0, c.clone(), ParityKind::E);
+        let f = terms(1.0, 1.0, 5.0, c, ParityKind::F);
+        assert!((f[0] - e[0] - 2.0 * (o + p + q)).abs() < 1e-2);
+
+        // Components are normalized and `Σ` states are not affected
+        let c = MolecularConstants {
+            b: 1.0,
+            a: 30.0,
+            p: 0.1,
+            q: 0.1,
+            ..Default::default()
+        };
+        let res = EffectiveHamiltonian::new(1.0, 1.0, 5.0, c.clone(), ParityKind::F).eval();
+        for (_, v) in res.into_iter().flatten() {
+            assert!((v.iter().map(|c| c * c).sum::<f64>() - 1.0).abs() < 1e-9);
+        }
+        assert_eq!(
+            terms(1.0, 0.0, 5.0, c.clone(), ParityKind::E),
+            terms(1.0, 0.0, 5.0, c, ParityKind::F)
+        );
+    }
+
+    #[test]
+    fn missing_levels() {
+        let c = MolecularConstants {
+            b: 1.0,
+            a: 30.0,
+            ..Default::default()
+        };
+        let res = EffectiveHamiltonian::new(1.5, 1.0, 0.5, c, ParityKind::E).eval();
+        assert!(res[0].is_some() && res[1].is_some());
+        assert!(res[2].is_none() && res[3].is_none());
+        assert_eq!(res[0].as_ref().unwrap().1.len(), 4);
+    }
+}
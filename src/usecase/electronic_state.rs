@@ -0,0 +1,461 @@
+use crate::{domain::constant::E, prelude::*};
+
+/// Rotational levels of a vibrational level
+/// * `0` - `J`: Rotational level
+/// * `1` - `i` of `Fi`
+/// * `2` - Parity
+/// * `3` - `Fi(J)`: Rotational term
+pub type RotationalLevel = (f64, usize, ParityKind, Term);
+
+/// Level structure of an electronic state
+#[derive(Debug, Clone)]
+pub struct ElectronicState {
+    pub spin_quantum_number_kind: SpinQuantumNumberKind,
+    /// `Λ`: Electronic state
+    pub l: f64,
+    /// `γ`: Spin-rotation constant (`cm^-1`)
+    pub r: f64,
+    /// Reflection symmetry, used when `Λ = 0`
+    pub symmetry_kind: SymmetryKind,
+    /// Dunham coefficients `Y_kl`
+    pub dunham_coefficients: DunhamCoefficients,
+    /// Model of the rotational term values
+    pub rotational_term_kind: RotationalTermKind,
+    /// `Te`: Minimum electronic energy (`cm^-1`)
+    pub t_e: Term,
+    /// `ge`: Electronic degeneracy, usually `(2S+1)(2-δ0,Λ)`
+    pub g: f64,
+    /// Nuclear spin statistics of the rotational levels
+    pub nuclear_spin_kind: NuclearSpinKind,
+}
+
+impl ElectronicState {
+    /// Returns `S`: Spin quantum number
+    pub fn spin(&self) -> f64 {
+        match self.spin_quantum_number_kind {
+            SpinQuantumNumberKind::Singlet => 0.0,
+            SpinQuantumNumberKind::Doublet => 0.5,
+            SpinQuantumNumberKind::Triplet => 1.0,
+            SpinQuantumNumberKind::Quartet => 1.5,
+        }
+    }
+
+    /// Returns the lowest `J`, `min |Ω|` over `Ω = Λ+Σ`
+    pub fn j_min(&self) -> f64 {
+        let s = self.spin();
+        (0..=(2.0 * s) as usize)
+            .map(|k| (self.l + k as f64 - s).abs())
+            .fold(f64::MAX, f64::min)
+    }
+
+    /// Returns `G(v)`: Vibrational term
+    pub fn vibrational_term(&self, v: u8) -> Term {
+        DunhamExpansion::new(v as f64, 0.0, self.dunham_coefficients.to_vec()).g_v()
+    }
+
+    /// Returns the parities of `Fi(J)`, both `e` and `f` for `Λ > 0`.
+    /// `Σ+` levels have the total parity `(-1)^N` and `Σ-` levels `-(-1)^N`.
+    pub fn parity_kinds(&self, j: f64, i: usize) -> Vec<ParityKind> {
+        if self.l > 0.0 {
+            return vec![ParityKind::E, ParityKind::F];
+        }
+
+        let s = self.spin();
+        let sign = match self.symmetry_kind {
+            SymmetryKind::Plus => 0,
+            SymmetryKind::Minus => 1,
+        };
+        // `(-1)^(N-J+s)` with `N = J-S+i-1`
+        let n = (i as f64 - 1.0 - s + j.fract()).round() as i64 + sign;
+        if n.rem_euclid(2) == 0 {
+            vec![ParityKind::E]
+        } else {
+            vec![ParityKind::F]
+        }
+    }
+
+    /// Returns the rotational term of `Fi(J)` in the vibrational level `v`, `NaN` if the
+    /// level does not exist
+    pub fn rotational_term(&self, v: u8, j: f64, i: usize, parity_kind: ParityKind) -> Term {
+        let params = self.dunham_coefficients.to_vec();
+        match (&self.rotational_term_kind, &self.spin_quantum_number_kind) {
+            (RotationalTermKind::Dunham, _) => DunhamExpansion::new(v as f64, j, params).f_v(),
+            (RotationalTermKind::HillVanVleck { a, p, q }, SpinQuantumNumberKind::Doublet) => {
+                let (f1, f2) = HillVanVleck::new(v as f64, j, self.l, a.clone(), self.r, params)
+                    .lambda_doubling(*p, *q, parity_kind)
+                    .eval();
+                if i == 1 {
+                    f1
+                } else {
+                    f2
+                }
+            }
+            (RotationalTermKind::Budo { a, lambda }, SpinQuantumNumberKind::Triplet) => {
+                let (f1, f2, f3) =
+                    Budo::new(v as f64, j, self.l, a.clone(), *lambda, self.r, params).eval();
+                match i {
+                    1 => f1,
+                    2 => f2,
+                    _ => f3,
+                }
+            }
+            (RotationalTermKind::EffectiveHamiltonian { .. }, _) => self
+                .effective_hamiltonian(v, j, parity_kind)
+                .and_then(|res| res.get(i - 1).cloned().flatten())
+                .map_or(Term::new(f64::NAN), |(e, _)| e),
+            (rotational_term_kind, spin_quantum_number_kind) => panic!(
+                "{:?} does not apply to a {:?} system",
+                rotational_term_kind, spin_quantum_number_kind
+            ),
+        }
+    }
+
+    /// Returns the term values and the eigenvectors of `F1`, ..., `F(2S+1)` at `J` = `j`
+    /// if the state is `RotationalTermKind::EffectiveHamiltonian`, with `Bv` and `Dv`
+    /// from the Dunham parameters
+    pub fn effective_hamiltonian(
+        &self,
+        v: u8,
+        j: f64,
+        parity_kind: ParityKind,
+    ) -> Option<RotationalLevels> {
+        let RotationalTermKind::EffectiveHamiltonian {
+            a,
+            a_d,
+            lambda,
+            p,
+            q,
+            o,
+        } = &self.rotational_term_kind
+        else {
+            return None;
+        };
+
+        let e = DunhamExpansion::new(v as f64, j, self.dunham_coefficients.to_vec());
+        let v = v as f64 + 0.5; // v+1/2
+        let constants = MolecularConstants {
+            b: e.b_v(),
+            d: e.d_v(),
+            a: a.iter()
+                .enumerate()
+                .map(|(k, &a)| a * v.powi(k as i32))
+                .sum(),
+            a_d: *a_d,
+            r: self.r,
+            lambda: *lambda,
+            p: *p,
+            q: *q,
+            o: *o,
+        };
+
+        Some(EffectiveHamiltonian::new(self.spin(), self.l, j, constants, parity_kind).eval())
+    }
+
+    /// Returns the existing levels `Fi(J)` of both parities in the vibrational level `v`,
+    /// in ascending order of `J` until `gns (2J+1) exp(-F(J)/Tr)` becomes negligible
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Vibrational level
+    /// * `t_r` - `Tr`: Rotational temperature (eV)
+    pub fn rotational_levels(&self, v: u8, t_r: Term) -> Vec<RotationalLevel> {
+        let s = self.spin();
+        let (mut res, mut sum) = (vec![], 0.0);
+        for n in 0..1000 {
+            let j = n as f64 + s.fract();
+            let levels = self.rotational_levels_of(v, j);
+            // `J` without levels for the nuclear spin statistics
+            let homonuclear = !matches!(self.nuclear_spin_kind, NuclearSpinKind::Heteronuclear);
+            if levels.is_empty() && homonuclear {
+                continue;
+            }
+            let w: f64 = levels
+                .iter()
+                .map(|&(j, _, parity_kind, e)| self.rotational_weight(j, parity_kind, e, t_r))
+                .sum();
+            res.extend(levels);
+            sum += w;
+            if n > 10 && w < sum * 1.0e-12 {
+                break;
+            }
+        }
+        res
+    }
+
+    /// Returns the existing levels `Fi(J)` of both parities at `J` = `j` in the vibrational
+    /// level `v`, without the levels of `gns = 0`
+    fn rotational_levels_of(&self, v: u8, j: f64) -> Vec<RotationalLevel> {
+        (1..=(2.0 * self.spin()) as usize + 1)
+            .filter(|&i| self.exists(j, i))
+            .flat_map(|i| {
+                self.parity_kinds(j, i)
+                    .into_iter()
+                    .map(move |parity_kind| (i, parity_kind))
+            })
+            .map(|(i, parity_kind)| {
+                (
+                    j,
+                    i,
+                    parity_kind,
+                    self.rotational_term(v, j, i, parity_kind),
+                )
+            })
+            .filter(|&(j, _, parity_kind, e)| {
+                e.unwrap().is_finite() && self.nuclear_spin_kind.weight(j, parity_kind) > 0.0
+            })
+            .collect()
+    }
+
+    /// Returns whether `Fi(J)` exists, i.e. whether `J >= |Ω|` for `Fi`
+    ///
+    /// `RotationalTermKind::Dunham` has no spin structure, so the levels of `|Ω| = |Λ+Σ| > J`
+    /// are counted off from the lowest `Fi` as in Hund's case (b), where `Fi` has
+    /// `N = J-S+i-1 >= Λ`. The other models give `NaN` for the levels which do not exist.
+    fn exists(&self, j: f64, i: usize) -> bool {
+        if !matches!(self.rotational_term_kind, RotationalTermKind::Dunham) {
+            return true;
+        }
+        let s = self.spin();
+        let n = (0..=(2.0 * s) as usize)
+            .filter(|&k| (self.l + k as f64 - s).abs() <= j)
+            .count();
+        i + n > (2.0 * s) as usize + 1
+    }
+
+    /// Returns `gns (2J+1) exp(-F(J)/Tr)` of the level `J` = `j` of `parity_kind` with the
+    /// rotational term `term`
+    fn rotational_weight(&self, j: f64, parity_kind: ParityKind, term: Term, t_r: Term) -> f64 {
+        self.nuclear_spin_kind.weight(j, parity_kind) * (2.0 * j + 1.0) * boltzmann(term, t_r)
+    }
+
+    /// Returns the existing levels `Fi(J)` of both parities in the vibrational level `v` with
+    /// their fractions `N(J)/N` of `rotational_distribution_kind`
+    ///
+    /// The levels are `rotational_levels` at the highest temperature of the Boltzmann
+    /// components, or those of `J` in the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Vibrational level
+    /// * `t_r` - `Tr`: Rotational temperature (eV)
+    /// * `rotational_distribution_kind` - Model of the rotational populations
+    pub fn rotational_populations(
+        &self,
+        v: u8,
+        t_r: Term,
+        rotational_distribution_kind: &RotationalDistributionKind,
+    ) -> Vec<(RotationalLevel, f64)> {
+        let levels: Vec<RotationalLevel> = match rotational_distribution_kind {
+            RotationalDistributionKind::Table(table) => table
+                .iter()
+                .flat_map(|&(j, _)| self.rotational_levels_of(v, j))
+                .collect(),
+            _ => {
+                let t_max = rotational_distribution_kind
+                    .components(t_r)
+                    .into_iter()
+                    .map(|(t, _)| t)
+                    .max_by(|a, b| a.unwrap().total_cmp(&b.unwrap()))
+                    .unwrap_or(t_r);
+                self.rotational_levels(v, t_max)
+            }
+        };
+        let population = self.rotational_population(v, t_r, rotational_distribution_kind);
+        levels
+            .into_iter()
+            .map(|level| (level, population(level.0, level.2, level.3)))
+            .collect()
+    }
+
+    /// Returns `N(J)/N` of `rotational_distribution_kind` as a function of `J`, the parity
+    /// and `F(J)` for the levels in the vibrational level `v`, with the partition functions
+    /// computed once
+    ///
+    /// The Boltzmann components include the nuclear spin weights `gns`, while the table gives
+    /// the populations as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Vibrational level
+    /// * `t_r` - `Tr`: Rotational temperature (eV)
+    /// * `rotational_distribution_kind` - Model of the rotational populations
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::*;
+    /// let state = ElectronicState {
+    ///     spin_quantum_number_kind: SpinQuantumNumberKind::Singlet,
+    ///     l: 0.0,
+    ///     r: 0.0,
+    ///     symmetry_kind: SymmetryKind::Plus,
+    ///     dunham_coefficients: DunhamCoefficients::new().with(0, 1, 2.0),
+    ///     rotational_term_kind: RotationalTermKind::Dunham,
+    ///     t_e: Term::new(0.0),
+    ///     g: 1.0,
+    ///     nuclear_spin_kind: NuclearSpinKind::Heteronuclear,
+    /// };
+    /// let table = RotationalDistributionKind::Table(vec![(1.0, 1.0), (2.0, 3.0)]);
+    /// let population = state.rotational_population(0, Term::new(0.1), &table);
+    /// let f = |j| state.rotational_term(0, j, 1, ParityKind::E);
+    /// assert_eq!(population(2.0, ParityKind::E, f(2.0)), 0.75);
+    /// assert_eq!(population(3.0, ParityKind::E, f(3.0)), 0.0);
+    /// ```
+    pub fn rotational_population(
+        &self,
+        v: u8,
+        t_r: Term,
+        rotational_distribution_kind: &RotationalDistributionKind,
+    ) -> impl Fn(f64, ParityKind, Term) -> f64 {
+        let components: Vec<(Term, f64)> = rotational_distribution_kind
+            .components(t_r)
+            .into_iter()
+            .map(|(t, f)| (t, f / self.rotational_partition_function(v, t)))
+            .collect();
+        let table: Vec<(f64, f64)> = match rotational_distribution_kind {
+            RotationalDistributionKind::Table(table) => {
+                let sum: f64 = table
+                    .iter()
+                    .map(|&(j, n)| n * self.rotational_levels_of(v, j).len() as f64)
+                    .sum();
+                table.iter().map(|&(j, n)| (j, n / sum)).collect()
+            }
+            _ => vec![],
+        };
+
+        let nuclear_spin_kind = self.nuclear_spin_kind.clone();
+        move |j, parity_kind, e| {
+            let n: f64 = components
+                .iter()
+                .map(|&(t, f)| f * (2.0 * j + 1.0) * boltzmann(e, t))
+                .sum();
+            let n = n * nuclear_spin_kind.weight(j, parity_kind);
+            n + table
+                .iter()
+                .find(|&&(u, _)| u == j)
+                .map_or(0.0, |&(_, n)| n)
+        }
+    }
+
+    /// Returns `Qr = Σ gns (2J+1) exp(-F(J)/Tr)` over `rotational_levels`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::*;
+    /// // `Qr = kT/hcB + 1/3` at `kT >> hcB`
+    /// let state = ElectronicState {
+    ///     spin_quantum_number_kind: SpinQuantumNumberKind::Singlet,
+    ///     l: 0.0,
+    ///     r: 0.0,
+    ///     symmetry_kind: SymmetryKind::Plus,
+    ///     dunham_coefficients: DunhamCoefficients::new().with(0, 1, 2.0),
+    ///     rotational_term_kind: RotationalTermKind::Dunham,
+    ///     t_e: Term::new(0.0),
+    ///     g: 1.0,
+    ///     nuclear_spin_kind: NuclearSpinKind::Heteronuclear,
+    /// };
+    /// let kt = 0.1 * 8065.544;
+    /// let q_r = state.rotational_partition_function(0, Term::new(0.1));
+    /// assert!((q_r / (kt / 2.0 + 1.0 / 3.0) - 1.0).abs() < 1e-4);
+    /// ```
+    pub fn rotational_partition_function(&self, v: u8, t_r: Term) -> f64 {
+        self.rotational_levels(v, t_r)
+            .into_iter()
+            .map(|(j, _, parity_kind, e)| self.rotational_weight(j, parity_kind, e, t_r))
+            .sum()
+    }
+
+    /// Returns the bound vibrational levels in ascending order of `v`, while `G(v)` increases
+    ///
+    /// With `VibrationalDistributionKind::Boltzmann` the levels end where `N(v)` becomes
+    /// negligible. The other distributions may grow again at high `v`, so they run up to the
+    /// last bound level.
+    ///
+    /// # Arguments
+    ///
+    /// * `t_v` - `Tv`: Vibrational temperature (eV)
+    /// * `vibrational_distribution_kind` - `N(v)`: Model of the vibrational populations
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::*;
+    /// // `G(v)` of `ωe = 1000` and `ωexe = 50` has its maximum at `v = 9.5`
+    /// let state = ElectronicState {
+    ///     spin_quantum_number_kind: SpinQuantumNumberKind::Singlet,
+    ///     l: 0.0,
+    ///     r: 0.0,
+    ///     symmetry_kind: SymmetryKind::Plus,
+    ///     dunham_coefficients: DunhamCoefficients::new().with(1, 0, 1000.0).with(2, 0, -50.0),
+    ///     rotational_term_kind: RotationalTermKind::Dunham,
+    ///     t_e: Term::new(0.0),
+    ///     g: 1.0,
+    ///     nuclear_spin_kind: NuclearSpinKind::Heteronuclear,
+    /// };
+    /// let table = VibrationalDistributionKind::Table(vec![(0, 1.0)]);
+    /// assert_eq!(state.vibrational_levels(Term::new(0.5), &table).len(), 10);
+    /// ```
+    pub fn vibrational_levels(
+        &self,
+        t_v: Term,
+        vibrational_distribution_kind: &VibrationalDistributionKind,
+    ) -> Vec<u8> {
+        let boltzmann = matches!(
+            vibrational_distribution_kind,
+            VibrationalDistributionKind::Boltzmann
+        );
+        let (mut res, mut sum) = (vec![], 0.0);
+        for v in 0..=u8::MAX {
+            if v > 0 && self.vibrational_term(v).unwrap() <= self.vibrational_term(v - 1).unwrap() {
+                break;
+            }
+            let n = self.vibrational_population(v, t_v, vibrational_distribution_kind);
+            res.push(v);
+            sum += n;
+            if boltzmann && v > 10 && n < sum * 1.0e-12 {
+                break;
+            }
+        }
+        res
+    }
+
+    /// Returns `Qv = Σ N(v)` over `v = 0, ..., v_max`
+    ///
+    /// # Arguments
+    ///
+    /// * `v_max` - Highest vibrational level
+    /// * `t_v` - `Tv`: Vibrational temperature (eV)
+    /// * `vibrational_distribution_kind` - `N(v)`: Model of the vibrational populations
+    pub fn vibrational_partition_function(
+        &self,
+        v_max: u8,
+        t_v: Term,
+        vibrational_distribution_kind: &VibrationalDistributionKind,
+    ) -> f64 {
+        (0..=v_max)
+            .map(|v| self.vibrational_population(v, t_v, vibrational_distribution_kind))
+            .sum()
+    }
+
+    /// Returns `N(v)`: Relative population of the vibrational level `v`
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Vibrational level
+    /// * `t_v` - `Tv`: Vibrational temperature (eV)
+    /// * `vibrational_distribution_kind` - Model of the vibrational populations
+    pub fn vibrational_population(
+        &self,
+        v: u8,
+        t_v: Term,
+        vibrational_distribution_kind: &VibrationalDistributionKind,
+    ) -> f64 {
+        vibrational_distribution_kind.eval(v, |v| self.vibrational_term(v), t_v)
+    }
+}
+
+/// Returns the Boltzmann factor `exp(-hcT/kT)` of the term `term` at the temperature `t` (eV)
+pub(crate) fn boltzmann(term: Term, t: Term) -> f64 {
+    (-term.to_jules() / (E * t.unwrap())).exp()
+}
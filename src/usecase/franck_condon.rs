@@ -0,0 +1,463 @@
+use std::{f64::consts::PI, fmt};
+
+use crate::{
+    domain::constant::{C, H, U},
+    prelude::{PotentialKind, Term, TransitionMomentKind},
+};
+
+/// Error of the grid or the levels of `Numerov` and `FranckCondon`
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumerovError {
+    /// Fewer grid points than the 3 of the Numerov method
+    TooFewPoints { n: usize },
+    /// The states of `FranckCondon` are on different grids
+    GridMismatch,
+    /// No level `v` below the potential at both ends of the grid
+    Unbound { v: u8 },
+}
+
+impl fmt::Display for NumerovError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewPoints { n } => write!(f, "{} grid points, at least 3 are required", n),
+            Self::GridMismatch => write!(f, "the states are on different grids"),
+            Self::Unbound { v } => write!(f, "v = {} is not bound on the grid", v),
+        }
+    }
+}
+
+impl std::error::Error for NumerovError {}
+
+/// Vibrational wave functions of a potential by the Numerov method
+///
+/// The eigenvalue of `v` is bracketed by the number of the nodes of the outward solution
+/// and the wave function is matched at the outer classical turning point.
+#[derive(Debug, Clone)]
+pub struct Numerov {
+    r: Vec<f64>,
+    u: Vec<f64>,
+    k: f64,
+}
+
+impl Numerov {
+    /// Returns the new Numerov instance
+    ///
+    /// # Arguments
+    ///
+    /// * `mu` - `μ`: Reduced mass (u)
+    /// * `potential_kind` - Potential energy curve
+    /// * `r_min` - Lower end of the grid (Å)
+    /// * `r_max` - Upper end of the grid (Å)
+    /// * `n` - Number of the grid points, at least 3
+    pub fn new(
+        mu: f64,
+        potential_kind: &PotentialKind,
+        r_min: f64,
+        r_max: f64,
+        n: usize,
+    ) -> Result<Self, NumerovError> {
+        if n < 3 {
+            return Err(NumerovError::TooFewPoints { n });
+        }
+        let r: Vec<f64> = (0..n)
+            .map(|i| r_min + (r_max - r_min) * i as f64 / (n - 1) as f64)
+            .collect();
+        let u = r.iter().map(|&r| potential_kind.eval(r)).collect();
+        // `h/(8π^2cμ)` (Å^2 cm^-1)
+        let k = H / (8.0 * PI.powi(2) * C * 100.0 * mu * U) * 1.0e20;
+        Ok(Self { r, u, k })
+    }
+
+    /// Returns the grid points (Å)
+    pub fn r(&self) -> &[f64] {
+        &self.r
+    }
+
+    /// Returns `G(v)` measured from the minimum of the potential and the wave function
+    /// normalized as `∫ψ^2 dr = 1` on the grid
+    ///
+    /// The level has to lie below the potential at both ends of the grid, otherwise it is
+    /// `NumerovError::Unbound`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{Numerov, NumerovError, PotentialKind};
+    /// // Morse: `G(v) = ωe(v+1/2) - ωexe(v+1/2)^2` with ωe = 2000, ωexe = 15
+    /// let morse = PotentialKind::Morse { de: 2000.0f64.powi(2) / 60.0, a: 2.4957, re: 1.1 };
+    /// let numerov = Numerov::new(7.0, &morse, 0.7, 2.0, 2000).unwrap();
+    /// let (g, _) = numerov.eval(0).unwrap();
+    /// assert!((g.unwrap() - (1000.0 - 3.75)).abs() < 0.5);
+    /// assert_eq!(numerov.eval(60).unwrap_err(), NumerovError::Unbound { v: 60 });
+    /// ```
+    pub fn eval(&self, v: u8) -> Result<(Term, Vec<f64>), NumerovError> {
+        let mut lo = self.u.iter().cloned().fold(f64::MAX, f64::min);
+        let mut hi = self.u[0].min(self.u[self.u.len() - 1]);
+        if self.nodes(hi) <= v as usize {
+            return Err(NumerovError::Unbound { v });
+        }
+        for _ in 0..200 {
+            let e = (lo + hi) / 2.0;
+            if self.nodes(e) > v as usize {
+                hi = e;
+            } else {
+                lo = e;
+            }
+        }
+        let e = (lo + hi) / 2.0;
+        Ok((Term::new(e), self.wave_function(e)))
+    }
+
+    /// Returns `1 - h^2 f/12` of the Numerov method with `ψ'' = fψ`
+    fn w(&self, e: f64) -> Vec<f64> {
+        let h = self.r[1] - self.r[0];
+        self.u
+            .iter()
+            .map(|&u| 1.0 - h * h * (u - e) / self.k / 12.0)
+            .collect()
+    }
+
+    /// Returns the number of the nodes of the outward solution at `e`
+    fn nodes(&self, e: f64) -> usize {
+        let w = self.w(e);
+        let (mut p0, mut p1) = (0.0, 1.0e-30);
+        let mut res = 0;
+        for i in 1..w.len() - 1 {
+            let p2 = ((12.0 - 10.0 * w[i]) * p1 - w[i - 1] * p0) / w[i + 1];
+            if p2 * p1 < 0.0 {
+                res += 1;
+            }
+            (p0, p1) = (p1, p2);
+            if p1.abs() > 1.0e100 {
+                (p0, p1) = (p0 * 1.0e-100, p1 * 1.0e-100);
+            }
+        }
+        res
+    }
+
+    /// Returns the normalized wave function at the eigenvalue `e`
+    fn wave_function(&self, e: f64) -> Vec<f64> {
+        let w = self.w(e);
+        let n = w.len();
+        let m = self
+            .u
+            .iter()
+            .rposition(|&u| u < e)
+            .unwrap_or(n / 2)
+            .clamp(1, n - 2);
+
+        let mut res = vec![0.0; n];
+        res[1] = 1.0e-30;
+        for i in 1..m {
+            res[i + 1] = ((12.0 - 10.0 * w[i]) * res[i] - w[i - 1] * res[i - 1]) / w[i + 1];
+            if res[i + 1].abs() > 1.0e100 {
+                res.iter_mut().take(i + 2).for_each(|v| *v *= 1.0e-100);
+            }
+        }
+        let mut inward = vec![0.0; n];
+        inward[n - 2] = 1.0e-30;
+        for i in (m + 1..n - 1).rev() {
+            inward[i - 1] =
+                ((12.0 - 10.0 * w[i]) * inward[i] - w[i + 1] * inward[i + 1]) / w[i - 1];
+            if inward[i - 1].abs() > 1.0e100 {
+                inward.iter_mut().skip(i - 1).for_each(|v| *v *= 1.0e-100);
+            }
+        }
+        let scale = res[m] / inward[m];
+        for i in m + 1..n {
+            res[i] = inward[i] * scale;
+        }
+
+        let h = self.r[1] - self.r[0];
+        let norm = (res.iter().map(|v| v * v).sum::<f64>() * h).sqrt();
+        res.into_iter().map(|v| v / norm).collect()
+    }
+}
+
+/// Franck-Condon factors and r-centroids between the vibrational levels of two states
+#[derive(Debug, Clone)]
+pub struct FranckCondon {
+    upper: Numerov,
+    lower: Numerov,
+}
+
+impl FranckCondon {
+    /// Returns the new Franck-Condon instance
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Wave functions of the upper state
+    /// * `lower` - Wave functions of the lower state, on the same grid as `upper`
+    pub fn new(upper: Numerov, lower: Numerov) -> Result<Self, NumerovError> {
+        if upper.r != lower.r {
+            return Err(NumerovError::GridMismatch);
+        }
+        Ok(Self { upper, lower })
+    }
+
+    /// Returns `[(v', v'', q, r-centroid (Å))]` of all the pairs of `vs_u` and `vs_l`,
+    /// with `q = (∫ψ'ψ''dr)^2` and `r-centroid = ∫ψ'rψ''dr / ∫ψ'ψ''dr`
+    pub fn eval(&self, vs_u: &[u8], vs_l: &[u8]) -> Result<Vec<(u8, u8, f64, f64)>, NumerovError> {
+        Ok(self
+            .integrals(vs_u, vs_l, |r| r)?
+            .into_iter()
+            .map(|(vu, vl, overlap, moment)| (vu, vl, overlap.powi(2), moment / overlap))
+            .collect())
+    }
+
+    /// Returns the Franck-Condon factors in the form of `Calc::q`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{FranckCondon, Numerov, PotentialKind};
+    /// let morse = PotentialKind::Morse { de: 60000.0, a: 2.0, re: 1.1 };
+    /// let upper = Numerov::new(7.0, &morse, 0.7, 2.0, 1000).unwrap();
+    /// let lower = Numerov::new(7.0, &morse, 0.7, 2.0, 1000).unwrap();
+    /// let q = FranckCondon::new(upper, lower).unwrap().q(&[0], &[0, 1]).unwrap();
+    /// assert!((q[0].2 - 1.0).abs() < 1e-6);
+    /// assert!(q[1].2 < 1e-6);
+    /// ```
+    pub fn q(&self, vs_u: &[u8], vs_l: &[u8]) -> Result<Vec<(u8, u8, f64)>, NumerovError> {
+        Ok(self
+            .eval(vs_u, vs_l)?
+            .into_iter()
+            .map(|(vu, vl, q, _)| (vu, vl, q))
+            .collect())
+    }
+
+    /// Returns the band strengths `(∫ψ'Re(r)ψ''dr)^2` (D^2) in the form of `Calc::q`
+    ///
+    /// Unlike `TransitionMomentKind::band_strengths`, the overlap with `Re(r)` is integrated
+    /// over the wave functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{
+    ///     FranckCondon, Numerov, PotentialKind, TransitionMomentKind,
+    /// };
+    /// let morse = PotentialKind::Morse { de: 60000.0, a: 2.0, re: 1.1 };
+    /// let upper = Numerov::new(7.0, &morse, 0.7, 2.0, 1000).unwrap();
+    /// let lower = Numerov::new(7.0, &morse, 0.7, 2.0, 1000).unwrap();
+    /// let re = TransitionMomentKind::Polynomial(vec![2.0]);
+    /// let res = FranckCondon::new(upper, lower)
+    ///     .unwrap()
+    ///     .band_strengths(&[0], &[0], &re)
+    ///     .unwrap();
+    /// assert!((res[0].2 - 4.0).abs() < 1e-6);
+    /// ```
+    pub fn band_strengths(
+        &self,
+        vs_u: &[u8],
+        vs_l: &[u8],
+        transition_moment_kind: &TransitionMomentKind,
+    ) -> Result<Vec<(u8, u8, f64)>, NumerovError> {
+        Ok(self
+            .integrals(vs_u, vs_l, |r| transition_moment_kind.eval(r))?
+            .into_iter()
+            .map(|(vu, vl, _, moment)| (vu, vl, moment.powi(2)))
+            .collect())
+    }
+
+    /// Returns `[(v', v'', ∫ψ'ψ''dr, ∫ψ'f(r)ψ''dr)]` of all the pairs of `vs_u` and `vs_l`
+    fn integrals(
+        &self,
+        vs_u: &[u8],
+        vs_l: &[u8],
+        f: impl Fn(f64) -> f64,
+    ) -> Result<Vec<(u8, u8, f64, f64)>, NumerovError> {
+        let h = self.upper.r[1] - self.upper.r[0];
+        let f: Vec<f64> = self.upper.r.iter().map(|&r| f(r)).collect();
+        let f = &f;
+        let psi_u = vs_u
+            .iter()
+            .map(|&v| self.upper.eval(v).map(|(_, psi)| psi))
+            .collect::<Result<Vec<_>, _>>()?;
+        let psi_l = vs_l
+            .iter()
+            .map(|&v| self.lower.eval(v).map(|(_, psi)| psi))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(vs_u
+            .iter()
+            .zip(psi_u.iter())
+            .flat_map(|(&vu, pu)| {
+                vs_l.iter().zip(psi_l.iter()).map(move |(&vl, pl)| {
+                    let (overlap, moment) = pu
+                        .iter()
+                        .zip(pl.iter())
+                        .zip(f.iter())
+                        .fold((0.0, 0.0), |(o, m), ((a, b), f)| {
+                            (o + a * b * h, m + a * b * f * h)
+                        });
+                    (vu, vl, overlap, moment)
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::{FranckCondon, Numerov, NumerovError};
+    use crate::{
+        domain::constant::{C, H, U},
+        prelude::{PotentialKind, SpectroscopicConstants, TransitionMomentKind},
+    };
+
+    fn morse(mu: f64, we: f64, wexe: f64, re: f64) -> PotentialKind {
+        let a = (2.0 * mu * U * 100.0 * C * wexe / H).sqrt() * 2.0 * PI * 1.0e-10;
+        PotentialKind::Morse {
+            de: we.powi(2) / (4.0 * wexe),
+            a,
+            re,
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let morse = morse(7.0, 2000.0, 15.0, 1.1);
+        assert_eq!(
+            Numerov::new(7.0, &morse, 0.7, 2.0, 2).unwrap_err(),
+            NumerovError::TooFewPoints { n: 2 }
+        );
+
+        // Bound levels are below the potential at `r_max`
+        let numerov = Numerov::new(7.0, &morse, 0.7, 1.3, 1000).unwrap();
+        assert!(numerov.eval(0).is_ok());
+        assert_eq!(
+            numerov.eval(20).unwrap_err(),
+            NumerovError::Unbound { v: 20 }
+        );
+
+        let upper = Numerov::new(7.0, &morse, 0.7, 2.0, 1000).unwrap();
+        let lower = Numerov::new(7.0, &morse, 0.7, 2.0, 1001).unwrap();
+        assert_eq!(
+            FranckCondon::new(upper, lower).unwrap_err(),
+            NumerovError::GridMismatch
+        );
+    }
+
+    #[test]
+    fn morse_levels() {
+        let (mu, we, wexe) = (7.0, 2000.0, 15.0);
+        let numerov = Numerov::new(mu, &morse(mu, we, wexe, 1.1), 0.7, 2.5, 3000).unwrap();
+        for v in 0..15 {
+            let (g, psi) = numerov.eval(v).unwrap();
+            let x = v as f64 + 0.5;
+            assert!((g.unwrap() - (we * x - wexe * x * x)).abs() < 0.05);
+            let nodes = psi.windows(2).filter(|p| p[0] * p[1] < 0.0).count();
+            assert_eq!(nodes, v as usize);
+        }
+    }
+
+    #[test]
+    fn displaced_harmonic() {
+        // `q(0, v'') = exp(-S) S^v'' / v''!` with `S = Δ^2 ωe/(4h/(8π^2cμ))`
+        let (mu, we, d): (f64, f64, f64) = (7.0, 2000.0, 0.05);
+        let k = H / (8.0 * PI.powi(2) * C * 100.0 * mu * U) * 1.0e20;
+        let harmonic = |re: f64| {
+            PotentialKind::tabulated(
+                (0..400)
+                    .map(|i| 0.6 + i as f64 * 0.004)
+                    .map(|r| (r, we.powi(2) / (4.0 * k) * (r - re).powi(2)))
+                    .collect(),
+            )
+        };
+        let upper = Numerov::new(mu, &harmonic(1.1 + d), 0.7, 1.5, 2000).unwrap();
+        let lower = Numerov::new(mu, &harmonic(1.1), 0.7, 1.5, 2000).unwrap();
+        let res = FranckCondon::new(upper, lower)
+            .unwrap()
+            .eval(&[0], &[0, 1, 2, 3])
+            .unwrap();
+
+        let s = d * d * we / (4.0 * k);
+        let mut factorial = 1.0;
+        for (i, (_, _, q, r)) in res.into_iter().enumerate() {
+            factorial *= i.max(1) as f64;
+            let e = (-s).exp() * s.powi(i as i32) / factorial;
+            assert!((q - e).abs() < 1e-4, "{} {} {}", i, q, e);
+            if i == 0 {
+                assert!((r - (1.1 + d / 2.0)).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn n2_c_b() {
+        // N₂ C³Πu - B³Πg: q(0, 0) = 0.4527
+        let mu = 7.00153700;
+        let upper = Numerov::new(mu, &morse(mu, 2047.17, 28.445, 1.1487), 0.8, 2.0, 2000).unwrap();
+        let lower = Numerov::new(mu, &morse(mu, 1733.39, 14.122, 1.2126), 0.8, 2.0, 2000).unwrap();
+        let res = FranckCondon::new(upper, lower)
+            .unwrap()
+            .eval(&[0], &[0, 1, 2])
+            .unwrap();
+        assert!((res[0].2 - 0.4527).abs() < 0.03);
+        // r-centroids: 1.185, 1.146, 1.113 Å
+        assert!((res[0].3 - 1.185).abs() < 0.01);
+        assert!(res[0].3 > res[1].3 && res[1].3 > res[2].3);
+    }
+
+    #[test]
+    fn potentials() {
+        // N₂ X¹Σg+: `G(v) - G(0)` of the potentials built from the constants
+        let constants = SpectroscopicConstants {
+            we: 2358.57,
+            wexe: 14.324,
+            be: 1.99824,
+            alpha_e: 0.017318,
+            ..Default::default()
+        };
+        let (mu, re) = (7.00153700, 1.09768);
+        let g = |v: f64| constants.we * v - constants.wexe * (v * (v + 1.0));
+        // The extended Morse oscillator does not fit `ωexe`
+        for (potential_kind, tolerance) in [
+            (PotentialKind::morse(&constants, re), 0.1),
+            (
+                PotentialKind::hulburt_hirschfelder(&constants, re, None),
+                0.1,
+            ),
+            (PotentialKind::extended_morse(&constants, re, None), 3.0),
+        ] {
+            let numerov = Numerov::new(mu, &potential_kind, 0.8, 1.8, 2000).unwrap();
+            let g0 = numerov.eval(0).unwrap().0.unwrap();
+            for v in 1..4 {
+                let gv = numerov.eval(v).unwrap().0.unwrap() - g0;
+                assert!((gv - g(v as f64)).abs() < tolerance);
+            }
+        }
+    }
+
+    #[test]
+    fn band_strengths() {
+        // The r-centroid approximation is exact for a linear `Re(r)`
+        let mu = 7.00153700;
+        let upper = Numerov::new(mu, &morse(mu, 2047.17, 28.445, 1.1487), 0.8, 2.0, 2000).unwrap();
+        let lower = Numerov::new(mu, &morse(mu, 1733.39, 14.122, 1.2126), 0.8, 2.0, 2000).unwrap();
+        let franck_condon = FranckCondon::new(upper, lower).unwrap();
+        let (vs_u, vs_l) = ([0, 1], [0, 1, 2, 3]);
+
+        let linear = TransitionMomentKind::Polynomial(vec![2.0, -1.0]);
+        let overlap = franck_condon.band_strengths(&vs_u, &vs_l, &linear).unwrap();
+        let centroid = linear.band_strengths(&franck_condon.eval(&vs_u, &vs_l).unwrap());
+        for (a, b) in overlap.iter().zip(centroid.iter()) {
+            assert_eq!((a.0, a.1), (b.0, b.1));
+            assert!((a.2 - b.2).abs() < 1e-9);
+        }
+
+        // A decreasing `Re(r)` favors the bands of small r-centroids, (0, v'') for large v''
+        let ratios: Vec<f64> = franck_condon
+            .q(&vs_u, &vs_l)
+            .unwrap()
+            .iter()
+            .zip(overlap.iter())
+            .take(vs_l.len())
+            .map(|(q, s)| s.2 / q.2)
+            .collect();
+        assert!(ratios.windows(2).all(|v| v[0] < v[1]));
+    }
+}
@@ -0,0 +1,263 @@
+use crate::prelude::{ParityKind, Term};
+
+type Params = Vec<Vec<f64>>;
+
+/// Hill-Van Vleck rotational term values of a doublet state
+#[derive(Debug, Clone)]
+pub struct HillVanVleck {
+    v: f64,
+    j: f64,
+    l: f64,
+    a: Vec<f64>,
+    r: f64,
+    params: Params,
+    p: f64,
+    q: f64,
+    parity_kind: ParityKind,
+}
+
+impl HillVanVleck {
+    /// Returns the new Hill-Van Vleck instance
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - Vibrational level
+    /// * `j` - Rotational level
+    /// * `l` - `Λ`: Electronic state
+    /// * `a` - `[Ae, αA, ...]`: Spin-orbit coupling constant `Av = Σ a_k (v+1/2)^k`
+    ///   (`cm^-1`)
+    /// * `r` - `γ`: Spin-rotation constant (`cm^-1`)
+    /// * `params` - Dunham parameters (see `DunhamExpansion`), the vibrational row is ignored
+    pub fn new(v: f64, j: f64, l: f64, a: Vec<f64>, r: f64, params: Params) -> Self {
+        Self {
+            v,
+            j,
+            l,
+            a,
+            r,
+            params,
+            p: 0.0,
+            q: 0.0,
+            parity_kind: ParityKind::E,
+        }
+    }
+
+    /// Returns the instance with the Λ-doubling of `²Π`, consuming `self` value
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - `p`: Λ-doubling constant (`cm^-1`)
+    /// * `q` - `q`: Λ-doubling constant (`cm^-1`)
+    /// * `parity_kind` - Parity of the levels
+    ///
+    /// The matrix elements are `∓(p+2q)(J+1/2)/2` for `²Π1/2` and
+    /// `∓q(J+1/2)√((J+1/2)^2-1)/2` between `²Π1/2` and `²Π3/2`, the upper sign for `e`.
+    /// States other than `²Π` are not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{HillVanVleck, ParityKind};
+    /// let e = |parity_kind| {
+    ///     HillVanVleck::new(0.0, 2.5, 1.0, vec![-100.0], 0.0, vec![vec![], vec![1.0]])
+    ///         .lambda_doubling(0.1, 0.0, parity_kind)
+    ///         .eval()
+    /// };
+    /// let (e, f) = (e(ParityKind::E), e(ParityKind::F));
+    /// assert!((e.0.unwrap() - f.0.unwrap()).abs() < 1e-3);
+    /// assert!((f.1.unwrap() - e.1.unwrap() - 0.3).abs() < 1e-2);
+    /// ```
+    pub fn lambda_doubling(self, p: f64, q: f64, parity_kind: ParityKind) -> Self {
+        Self {
+            p,
+            q,
+            parity_kind,
+            ..self
+        }
+    }
+
+    /// Returns the rotational terms `(F1, F2)`, consuming `self` value
+    ///
+    /// The levels which do not exist at low `J` are `NaN`; at `J = Λ-1/2` `F2` is missing
+    /// if `Y = A/B > 2` and `F1` otherwise.
+    ///
+    /// `F1` and `F2` are the eigenvalues of the `²Λ` Hamiltonian with `Bv`, `Av` and `γ`
+    /// divided by `Bv`, substituted into the Dunham rotational series in place of
+    /// `J(J+1)`. For `²Σ` it gives `F1 = BvN(N+1) + γN/2` and
+    /// `F2 = BvN(N+1) - γ(N+1)/2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::HillVanVleck;
+    /// // ²Σ, N = 3
+    /// let params = vec![vec![], vec![2.0]];
+    /// let (f1, _) = HillVanVleck::new(0.0, 3.5, 0.0, vec![], 0.1, params.clone()).eval();
+    /// assert!((f1.unwrap() - 24.15).abs() < 1e-12);
+    /// let (_, f2) = HillVanVleck::new(0.0, 2.5, 0.0, vec![], 0.1, params).eval();
+    /// assert!((f2.unwrap() - 23.8).abs() < 1e-12);
+    /// ```
+    pub fn eval(self) -> (Term, Term) {
+        let v = self.v + 0.5; // v+1/2
+        let y: Vec<f64> = self
+            .params
+            .iter()
+            .map(|vec| {
+                vec.iter()
+                    .enumerate()
+                    .map(|(k, &y)| y * v.powi(k as i32))
+                    .sum()
+            })
+            .collect();
+        let b = y.get(1).copied().unwrap_or(0.0);
+        let a: f64 = self
+            .a
+            .iter()
+            .enumerate()
+            .map(|(k, &a)| a * v.powi(k as i32))
+            .sum();
+
+        let Self {
+            j,
+            l,
+            r,
+            p,
+            q,
+            parity_kind,
+            ..
+        } = self;
+
+        // `|Ω=Λ-1/2⟩` and `|Ω=Λ+1/2⟩`
+        let x = (j + 0.5).powi(2) - l.powi(2);
+        let mut h11 = -a * l / 2.0 + b * (x + l) - r * (l + 1.0) / 2.0;
+        let h22 = a * l / 2.0 + b * (x - l) + r * (l - 1.0) / 2.0;
+        let mut h12 = -(b - r / 2.0) * x.sqrt();
+        if l == 1.0 {
+            let sign = match parity_kind {
+                ParityKind::E => 1.0,
+                ParityKind::F => -1.0,
+            };
+            h11 -= sign * (p + 2.0 * q) * (j + 0.5) / 2.0;
+            h12 -= sign * q * (j + 0.5) * x.sqrt() / 2.0;
+        }
+        let m = (h11 + h22) / 2.0;
+        let d = ((h22 - h11).powi(2) / 4.0 + h12.powi(2)).sqrt();
+        let nan = Term::new(f64::NAN);
+
+        let f = |e: f64| {
+            if b == 0.0 {
+                return Term::new(e);
+            }
+            let u = e / b;
+            let res = y
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(k, &y)| y * u.powi(k as i32))
+                .sum();
+            Term::new(res)
+        };
+
+        // Only `Ω = Λ-1/2` exists at `J = Λ-1/2`, and no level below
+        if x < 0.0 {
+            return (nan, nan);
+        }
+        if x == 0.0 {
+            return if h11 <= h22 {
+                (f(h11), nan)
+            } else {
+                (nan, f(h11))
+            };
+        }
+        (f(m - d), f(m + d))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HillVanVleck;
+
+    #[test]
+    fn sigma() {
+        let (b, r) = (1.9, 0.0073);
+        for n in (1..30).map(|v| v as f64) {
+            let (f1, _) =
+                HillVanVleck::new(0.0, n + 0.5, 0.0, vec![], r, vec![vec![], vec![b]]).eval();
+            let (_, f2) =
+                HillVanVleck::new(0.0, n - 0.5, 0.0, vec![], r, vec![vec![], vec![b]]).eval();
+            let e1 = b * n * (n + 1.0) + r * n / 2.0;
+            let e2 = b * n * (n + 1.0) - r * (n + 1.0) / 2.0;
+            assert!((f1.unwrap() - e1).abs() < 1e-9);
+            assert!((f2.unwrap() - e2).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn pi() {
+        // Hill-Van Vleck: Bv{(J+1/2)^2 - Λ^2 ∓ √(4(J+1/2)^2 + Y(Y-4)Λ^2)/2}
+        let (b, a) = (14.19, 28.0);
+        let y = a / b;
+        for j in (1..30).map(|v| v as f64 + 0.5) {
+            let (f1, f2) =
+                HillVanVleck::new(0.0, j, 1.0, vec![a], 0.0, vec![vec![], vec![b]]).eval();
+            let s = (4.0 * (j + 0.5).powi(2) + y * (y - 4.0)).sqrt() / 2.0;
+            assert!((f1.unwrap() - b * ((j + 0.5).powi(2) - 1.0 - s)).abs() < 1e-9);
+            assert!((f2.unwrap() - b * ((j + 0.5).powi(2) - 1.0 + s)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn missing_levels() {
+        // Only `²Π1/2` exists at `J = 1/2`, `F2` if `Y < 2` and `F1` otherwise
+        let b = 14.19;
+        for (a, i) in [(28.0, 2), (100.0, 1)] {
+            let (f1, f2) =
+                HillVanVleck::new(0.0, 0.5, 1.0, vec![a], 0.0, vec![vec![], vec![b]]).eval();
+            let (e, nan) = if i == 1 { (f1, f2) } else { (f2, f1) };
+            assert!((e.unwrap() - (b - a / 2.0)).abs() < 1e-9);
+            assert!(nan.unwrap().is_nan());
+        }
+        let (f1, f2) =
+            HillVanVleck::new(0.0, 0.5, 2.0, vec![28.0], 0.0, vec![vec![], vec![b]]).eval();
+        assert!(f1.unwrap().is_nan() && f2.unwrap().is_nan());
+    }
+
+    #[test]
+    fn vibration() {
+        // `Bv = Be - αe(v+1/2)` and `-Dv u^2`
+        let params = vec![vec![], vec![2.0, -0.1], vec![-1.0e-4]];
+        let (f1, _) = HillVanVleck::new(1.0, 2.5, 0.0, vec![], 0.0, params).eval();
+        let u = 6.0;
+        assert!((f1.unwrap() - (1.85 * u - 1.0e-4 * u * u)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn spin_orbit_vibration() {
+        // `Av = Ae + αA(v+1/2)`
+        let params = vec![vec![], vec![14.19]];
+        let a = vec![28.0, -0.4];
+        let e = HillVanVleck::new(2.0, 5.5, 1.0, vec![27.0], 0.0, params.clone()).eval();
+        let res = HillVanVleck::new(2.0, 5.5, 1.0, a, 0.0, params).eval();
+        assert!((res.0.unwrap() - e.0.unwrap()).abs() < 1e-9);
+        assert!((res.1.unwrap() - e.1.unwrap()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn lambda_doubling() {
+        use crate::prelude::ParityKind;
+
+        // Case (a) limit: `²Π1/2` splits by `(p+2q)(J+1/2)`, `²Π3/2` hardly
+        let (p, q) = (0.2, 0.01);
+        for j in (1..10).map(|v| v as f64 + 0.5) {
+            let e = |parity_kind| {
+                HillVanVleck::new(0.0, j, 1.0, vec![1.0e4], 0.0, vec![vec![], vec![1.0]])
+                    .lambda_doubling(p, q, parity_kind)
+                    .eval()
+            };
+            let (e, f) = (e(ParityKind::E), e(ParityKind::F));
+            let d = f.0.unwrap() - e.0.unwrap();
+            assert!((d / ((p + 2.0 * q) * (j + 0.5)) - 1.0).abs() < 1e-2);
+            assert!((f.1.unwrap() - e.1.unwrap()).abs() < 1e-2);
+        }
+    }
+}
@@ -1,7 +1,152 @@
 pub mod doublet;
+pub mod general;
 pub mod singlet;
 pub mod triplet;
 
 pub trait HonlLondonFactorImpl {
     fn eval(self) -> f64;
 }
+
+/// Returns the direction cosine matrix element `√(2J''+1)⟨J'' Ω'' 1 q|J' Ω'⟩`
+/// between the Hund's case (a) levels `|J'' Ω''⟩` and `|J' Ω''+q⟩`.
+/// The square is the Hönl-London factor of a single `Ω' - Ω''` sub-band.
+///
+/// # Arguments
+///
+/// * `j` - `J''`: Lower rotational level
+/// * `omega` - `Ω''`: Lower projection of `J` on the internuclear axis
+/// * `q` - `Ω' - Ω''` (`-1`, `0` or `1`)
+/// * `dj` - `J' - J''` (`-1`, `0` or `1`)
+///
+/// Returns `0` when either level does not exist.
+pub(crate) fn direction_cosine(j: f64, omega: f64, q: f64, dj: f64) -> f64 {
+    // Clebsch-Gordan coefficients <j m-q 1 q|j+dj m>
+    let m = omega + q;
+    if j < omega.abs() || j + dj < m.abs() || (j == 0.0 && dj == 0.0) {
+        return 0.0;
+    }
+    let cg = match (dj as i8, q as i8) {
+        (1, 1) => ((j + m) * (j + m + 1.0) / ((2.0 * j + 1.0) * (2.0 * j + 2.0))).sqrt(),
+        (1, 0) => ((j - m + 1.0) * (j + m + 1.0) / ((2.0 * j + 1.0) * (j + 1.0))).sqrt(),
+        (1, -1) => ((j - m) * (j - m + 1.0) / ((2.0 * j + 1.0) * (2.0 * j + 2.0))).sqrt(),
+        (0, 1) => -((j + m) * (j - m + 1.0) / (2.0 * j * (j + 1.0))).sqrt(),
+        (0, 0) => m / (j * (j + 1.0)).sqrt(),
+        (0, -1) => ((j - m) * (j + m + 1.0) / (2.0 * j * (j + 1.0))).sqrt(),
+        (-1, 1) => ((j - m) * (j - m + 1.0) / (2.0 * j * (2.0 * j + 1.0))).sqrt(),
+        (-1, 0) => -((j - m) * (j + m) / (j * (2.0 * j + 1.0))).sqrt(),
+        (-1, -1) => ((j + m + 1.0) * (j + m) / (2.0 * j * (2.0 * j + 1.0))).sqrt(),
+        _ => 0.0,
+    };
+    (2.0 * j + 1.0).sqrt() * cg
+}
+
+/// Returns the Wigner 3j symbol `(j1 j2 j3; m1 m2 m3)` by Racah's formula
+///
+/// # Examples
+///
+/// ```
+/// use emission_spectrum_lib_rs::usecase::honl_london_factor::wigner_3j;
+/// assert!((wigner_3j(1.0, 1.0, 0.0, 0.0, 0.0, 0.0) + 1.0 / 3f64.sqrt()).abs() < 1e-12);
+/// assert_eq!(wigner_3j(1.0, 1.0, 3.0, 0.0, 0.0, 0.0), 0.0);
+/// ```
+pub fn wigner_3j(j1: f64, j2: f64, j3: f64, m1: f64, m2: f64, m3: f64) -> f64 {
+    let is_int = |x: f64| (x - x.round()).abs() < 1e-9;
+    if (m1 + m2 + m3).abs() > 1e-9
+        || j3 < (j1 - j2).abs() - 1e-9
+        || j3 > j1 + j2 + 1e-9
+        || m1.abs() > j1 + 1e-9
+        || m2.abs() > j2 + 1e-9
+        || m3.abs() > j3 + 1e-9
+        || ![j1 + j2 + j3, j1 - m1, j2 - m2, j3 - m3]
+            .into_iter()
+            .all(is_int)
+    {
+        return 0.0;
+    }
+
+    // ln(n!)
+    let lf = |n: f64| (1..=n.round() as u64).map(|k| (k as f64).ln()).sum::<f64>();
+    let pre = 0.5
+        * (lf(j1 + j2 - j3) + lf(j1 - j2 + j3) + lf(-j1 + j2 + j3) - lf(j1 + j2 + j3 + 1.0)
+            + lf(j1 + m1)
+            + lf(j1 - m1)
+            + lf(j2 + m2)
+            + lf(j2 - m2)
+            + lf(j3 + m3)
+            + lf(j3 - m3));
+    let k_min = [0.0, j2 - j3 - m1, j1 - j3 + m2]
+        .into_iter()
+        .fold(f64::MIN, f64::max)
+        .round() as i64;
+    let k_max = [j1 + j2 - j3, j1 - m1, j2 + m2]
+        .into_iter()
+        .fold(f64::MAX, f64::min)
+        .round() as i64;
+    let sum: f64 = (k_min..=k_max)
+        .map(|k| {
+            let k = k as f64;
+            let sign = if k as i64 % 2 == 0 { 1.0 } else { -1.0 };
+            sign * (pre
+                - lf(k)
+                - lf(j1 + j2 - j3 - k)
+                - lf(j1 - m1 - k)
+                - lf(j2 + m2 - k)
+                - lf(j3 - j2 + m1 + k)
+                - lf(j3 - j1 - m2 + k))
+            .exp()
+        })
+        .sum();
+    let sign = if (j1 - j2 - m3).round() as i64 % 2 == 0 {
+        1.0
+    } else {
+        -1.0
+    };
+    sign * sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{direction_cosine, wigner_3j};
+
+    #[test]
+    fn test_wigner_3j() {
+        assert!((wigner_3j(0.5, 0.5, 1.0, 0.5, -0.5, 0.0) - 1.0 / 6f64.sqrt()).abs() < 1e-12);
+        assert!((wigner_3j(2.0, 1.0, 1.0, 0.0, 0.0, 0.0) - (2.0 / 15f64).sqrt()).abs() < 1e-12);
+        assert_eq!(wigner_3j(1.0, 1.0, 1.0, 0.0, 0.0, 0.0), 0.0);
+
+        // Orthogonality Σ (2j3+1)(j1 j2 j3; m1 m2 m3)^2 = 1
+        let (j1, j2, m1, m2) = (40.5, 1.5, -3.5, 0.5);
+        let s: f64 = (39..=42)
+            .map(|j3| j3 as f64)
+            .map(|j3| (2.0 * j3 + 1.0) * wigner_3j(j1, j2, j3, m1, m2, -m1 - m2).powi(2))
+            .sum();
+        assert!((s - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_direction_cosine() {
+        // √((2J''+1)(2J'+1)) (-1)^(J''-1+Ω') (J'' 1 J'; Ω'' q -Ω')
+        for j in (0..20).flat_map(|v| [v as f64, v as f64 + 0.5]) {
+            for omega in (-4..=4).map(|v| v as f64 * 0.5) {
+                for (q, dj) in [-1.0, 0.0, 1.0]
+                    .into_iter()
+                    .flat_map(|q| [(q, -1.0), (q, 0.0), (q, 1.0)])
+                {
+                    if j + dj < 0.0 || (omega - j).fract() != 0.0 {
+                        continue;
+                    }
+                    let o = omega + q;
+                    let phase = if (j - 1.0 + o).round() as i64 % 2 == 0 {
+                        1.0
+                    } else {
+                        -1.0
+                    };
+                    let e = ((2.0 * j + 1.0) * (2.0 * (j + dj) + 1.0)).sqrt()
+                        * phase
+                        * wigner_3j(j, 1.0, j + dj, omega, q, -o);
+                    assert!((direction_cosine(j, omega, q, dj) - e).abs() < 1e-10);
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::HonlLondonFactorImpl;
+use super::{direction_cosine, HonlLondonFactorImpl};
 use crate::prelude::{
     CouplingKind,
     DoubletBranchKind::{self, *},
@@ -6,7 +6,7 @@ use crate::prelude::{
 
 #[derive(Debug, Clone)]
 pub struct HonlLondonFactor {
-    /// `J'`: Quantum number of rotation
+    /// `J''`: Quantum number of rotation
     pub j: f64,
     /// `Λ'`: Electronic state
     pub lu: f64,
@@ -19,99 +19,177 @@ pub struct HonlLondonFactor {
 }
 
 impl HonlLondonFactorImpl for HonlLondonFactor {
+    /// Returns the line strength normalized as `Σ S = 2J''+1` over all the branches
+    /// (including the satellites) from a lower level.
     fn eval(self) -> f64 {
-        #[allow(unused)]
         let Self {
             j,
             lu,
             ll,
-            r,
             coupling_kind,
             branch_kind,
+            ..
         } = self;
 
         let dl = lu - ll;
 
-        let up = match coupling_kind {
-            CouplingKind::A => unimplemented!(),
-            CouplingKind::B => |j: f64, l: f64| 2.0 * (j - l + 0.5),
-        };
-        let um = match coupling_kind {
-            CouplingKind::A => unimplemented!(),
-            CouplingKind::B => |j: f64, l: f64| 2.0 * (j + l + 0.5),
+        // Levels which do not exist, `J < |Λ-1/2|`
+        if j + branch_kind.delta_j() < (lu - 0.5).abs() || j < (ll - 0.5).abs() {
+            return 0.0;
+        }
+
+        let (i, k) = branch_kind.levels();
+        let (i, k) = (i - 1, k - 1);
+        // The closed forms hold where both `F1` and `F2` exist, `J >= Λ+1/2`
+        let closed = dl == 1.0 && i == k && j + branch_kind.delta_j() >= lu + 0.5 && j >= ll + 0.5;
+
+        let (yu, yl) = match coupling_kind {
+            CouplingKind::Intermediate { yu, yl } if closed => (yu, yl),
+            CouplingKind::B if closed => (0.0, 0.0),
+            _ => {
+                let (yu, yl) = match coupling_kind {
+                    CouplingKind::A => (None, None),
+                    CouplingKind::B => (Some(0.0), Some(0.0)),
+                    CouplingKind::Intermediate { yu, yl } => (Some(yu), Some(yl)),
+                };
+                return line_strength(lu, ll, yu, yl, j, branch_kind.delta_j(), i, k);
+            }
         };
-        let cp = match coupling_kind {
-            CouplingKind::A => unimplemented!(),
-            CouplingKind::B => |j: f64, l: f64| 4.0 * (j + 0.5) * (j - l + 0.5),
+
+        // Earls' intermediate coupling with `Y = A/B`, `Y = 0` for case (b)
+        let x =
+            |j: f64, l: f64, y: f64| (l.powi(2) * y * (y - 4.0) + 4.0 * (j + 0.5).powi(2)).sqrt();
+        let up = |j: f64, l: f64, y: f64| x(j, l, y) + l * (y - 2.0);
+        let um = |j: f64, l: f64, y: f64| x(j, l, y) - l * (y - 2.0);
+        let cp = |j: f64, l: f64, y: f64| {
+            0.5 * (up(j, l, y).powi(2) + 4.0 * ((j + 0.5).powi(2) - l.powi(2)))
         };
-        let cm = match coupling_kind {
-            CouplingKind::A => unimplemented!(),
-            CouplingKind::B => |j: f64, l: f64| 4.0 * (j + 0.5) * (j + l + 0.5),
+        let cm = |j: f64, l: f64, y: f64| {
+            0.5 * (um(j, l, y).powi(2) + 4.0 * ((j + 0.5).powi(2) - l.powi(2)))
         };
 
-        if dl == 1.0 {
-            match branch_kind {
-                P1 => {
-                    return (j - ll - 1.5)
-                        * (j - ll - 0.5)
-                        * (um(j - 1.0, lu) * um(j, ll) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
-                            .powi(2)
-                        / (8.0 * j * cm(j - 1.0, lu) * cm(j, ll))
-                }
-                Q1 => {
-                    return (j + 0.5)
-                        * (j - ll - 0.5)
-                        * (j + ll + 1.5)
-                        * (up(j - 1.0, lu) * up(j, ll) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
-                            .powi(2)
-                        / (4.0 * j * (j + 1.0) * cm(j, lu) * cm(j, ll))
-                }
-                R1 => {
-                    return (j + ll + 1.5)
-                        * (j + ll + 2.5)
-                        * (um(j + 1.0, lu) * um(j, ll) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
-                            .powi(2)
-                        / (8.0 * (j + 1.0) * cm(j + 1.0, lu) * cm(j, ll))
-                }
-                P2 => {
-                    return (j - ll - 1.5)
-                        * (j - ll - 0.5)
-                        * (up(j - 1.0, lu) * up(j, ll) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
-                            .powi(2)
-                        / (8.0 * j * cp(j - 1.0, lu) * cp(j, ll))
-                }
-                Q2 => {
-                    return (j + 0.5)
-                        * (j - ll - 0.5)
-                        * (j + ll + 1.5)
-                        * (up(j - 1.0, lu) * up(j, ll) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
-                            .powi(2)
-                        / (4.0 * j * (j + 1.0) * cp(j, lu) * cp(j, ll))
-                }
-                R2 => {
-                    return (j + ll + 1.5)
-                        * (j + ll + 2.5)
-                        * (up(j + 1.0, lu) * up(j, ll) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
-                            .powi(2)
-                        / (8.0 * (j + 1.0) * cp(j + 1.0, lu) * cp(j, ll))
-                }
+        match branch_kind {
+            P1 => {
+                (j - ll - 1.5)
+                    * (j - ll - 0.5)
+                    * (um(j - 1.0, lu, yu) * um(j, ll, yl) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
+                        .powi(2)
+                    / (8.0 * j * cm(j - 1.0, lu, yu) * cm(j, ll, yl))
+            }
+            Q1 => {
+                (j + 0.5)
+                    * (j - ll - 0.5)
+                    * (j + ll + 1.5)
+                    * (um(j, lu, yu) * um(j, ll, yl) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
+                        .powi(2)
+                    / (4.0 * j * (j + 1.0) * cm(j, lu, yu) * cm(j, ll, yl))
+            }
+            R1 => {
+                (j + ll + 1.5)
+                    * (j + ll + 2.5)
+                    * (um(j + 1.0, lu, yu) * um(j, ll, yl) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
+                        .powi(2)
+                    / (8.0 * (j + 1.0) * cm(j + 1.0, lu, yu) * cm(j, ll, yl))
             }
+            P2 => {
+                (j - ll - 1.5)
+                    * (j - ll - 0.5)
+                    * (up(j - 1.0, lu, yu) * up(j, ll, yl) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
+                        .powi(2)
+                    / (8.0 * j * cp(j - 1.0, lu, yu) * cp(j, ll, yl))
+            }
+            Q2 => {
+                (j + 0.5)
+                    * (j - ll - 0.5)
+                    * (j + ll + 1.5)
+                    * (up(j, lu, yu) * up(j, ll, yl) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
+                        .powi(2)
+                    / (4.0 * j * (j + 1.0) * cp(j, lu, yu) * cp(j, ll, yl))
+            }
+            R2 => {
+                (j + ll + 1.5)
+                    * (j + ll + 2.5)
+                    * (up(j + 1.0, lu, yu) * up(j, ll, yl) + 4.0 * (j - ll + 0.5) * (j + ll + 0.5))
+                        .powi(2)
+                    / (8.0 * (j + 1.0) * cp(j + 1.0, lu, yu) * cp(j, ll, yl))
+            }
+            O12 | P12 | Q12 | Q21 | R21 | S21 => unreachable!(),
         }
+    }
+}
 
-        unimplemented!()
+/// Returns the Hund's case (a) components `[Ω=Λ-1/2, Ω=Λ+1/2]` of `F1` and `F2` from
+/// Earls' intermediate coupling with `Y = A/B`, or `None` for the levels which do not
+/// exist at `J`.
+///
+/// `²Λ` (`Λ > 0`) is in pure case (a) when `y` is `None`, `²Σ` is always in case (b).
+/// At `J = Λ-1/2` only `Ω = Λ-1/2` exists, which is `F1` if `Y > 2` and `F2` otherwise.
+fn components(l: f64, y: Option<f64>, j: f64) -> [Option<[f64; 2]>; 2] {
+    if j < l - 0.5 {
+        return [None, None];
+    }
+    let y = match (y, l) {
+        (None, l) if l > 0.0 => {
+            // F1 = ²Λ(Λ-1/2) and F2 = ²Λ(Λ+1/2) (regular)
+            let f2 = if j < l + 0.5 { None } else { Some([0.0, 1.0]) };
+            return [Some([1.0, 0.0]), f2];
+        }
+        (y, _) => y.unwrap_or(0.0),
+    };
+    if j < l + 0.5 {
+        return if y > 2.0 {
+            [Some([1.0, 0.0]), None]
+        } else {
+            [None, Some([1.0, 0.0])]
+        };
+    }
+
+    let x = (l.powi(2) * y * (y - 4.0) + 4.0 * (j + 0.5).powi(2)).sqrt();
+    let a = 2.0 * ((j + 0.5).powi(2) - l.powi(2)).sqrt();
+    let um = x - l * (y - 2.0);
+    let up = x + l * (y - 2.0);
+    let cm = (a.powi(2) + um.powi(2)).sqrt();
+    let cp = (a.powi(2) + up.powi(2)).sqrt();
+    [Some([a / cm, um / cm]), Some([a / cp, -up / cp])]
+}
+
+/// Returns the line strength of `Fi'(J''+dj) - Fk''(J'')`
+#[allow(clippy::too_many_arguments)]
+fn line_strength(
+    lu: f64,
+    ll: f64,
+    yu: Option<f64>,
+    yl: Option<f64>,
+    j: f64,
+    dj: f64,
+    i: usize,
+    k: usize,
+) -> f64 {
+    let q = lu - ll;
+    if q.abs() > 1.0 || j + dj < 0.0 {
+        return 0.0;
+    }
+
+    match (components(lu, yu, j + dj)[i], components(ll, yl, j)[k]) {
+        (Some(cu), Some(cl)) => (0..2)
+            .map(|s| cu[s] * cl[s] * direction_cosine(j, ll + s as f64 - 0.5, q, dj))
+            .sum::<f64>()
+            .powi(2),
+        _ => 0.0,
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::f64::{INFINITY, NAN};
-
     use crate::{
-        prelude::DoubletBranchKind::{self, *},
+        prelude::{
+            CouplingKind,
+            DoubletBranchKind::{self, *},
+        },
         usecase::honl_london_factor::HonlLondonFactorImpl,
     };
 
-    use super::HonlLondonFactor;
+    use super::{line_strength, HonlLondonFactor};
 
     fn init(branch_kind: DoubletBranchKind) -> Vec<f64> {
         let v: Vec<i64> = (0..35).collect();
@@ -124,7 +202,7 @@ mod tests {
                     lu: 2.0,
                     ll: 1.0,
                     r: 0.0,
-                    coupling_kind: crate::prelude::CouplingKind::B,
+                    coupling_kind: CouplingKind::B,
                     branch_kind: branch_kind.clone(),
                 };
                 s.eval()
@@ -157,7 +235,7 @@ mod tests {
         assert!(assert_vec_includes_nan(
             p1,
             vec![
-                INFINITY,
+                0.0,
                 -0.0,
                 0.0,
                 0.38095238095238093,
@@ -203,9 +281,9 @@ mod tests {
         assert!(assert_vec_includes_nan(
             p2,
             vec![
-                NAN,
                 0.0,
-                NAN,
+                0.0,
+                0.2666666666666666,
                 0.6428571428571429,
                 1.0666666666666667,
                 1.5151515151515151,
@@ -251,39 +329,39 @@ mod tests {
             vec![
                 -0.0,
                 0.0,
-                0.1523809523809524,
-                0.5142857142857142,
-                1.0343434343434343,
-                1.665001665001665,
-                2.3736263736263736,
-                3.138562091503268,
-                3.945235638114895,
-                4.7835953520164045,
-                5.64652738565782,
-                6.528874024526198,
-                7.426813186813187,
-                8.337456668491152,
-                9.258583611420097,
-                10.18845954804209,
-                11.125710890416773,
-                12.06923615344668,
-                13.018142028668345,
-                13.97169659608684,
-                14.929294566276983,
-                15.890431105800165,
-                16.854681878918697,
-                17.821687653784917,
-                18.791142303075077,
-                19.762783358787797,
-                20.736384509969415,
-                21.711749593419285,
-                22.688707742471315,
-                23.66710944198956,
-                24.64682329833044,
-                25.627733377733378,
-                26.609736999903813,
-                27.59274289858708,
-                28.57666967991524
+                1.8666666666666667,
+                3.2142857142857144,
+                4.4,
+                5.515151515151516,
+                6.593406593406593,
+                7.65,
+                8.69281045751634,
+                9.726315789473684,
+                10.753246753246753,
+                11.77536231884058,
+                12.793846153846154,
+                13.80952380952381,
+                14.822988505747126,
+                15.834677419354838,
+                16.844919786096256,
+                17.853968253968254,
+                18.862019914651494,
+                19.869230769230768,
+                20.875725900116144,
+                21.881606765327696,
+                22.88695652173913,
+                23.891843971631207,
+                24.896326530612246,
+                25.900452488687783,
+                26.904262753319358,
+                27.90779220779221,
+                28.911070780399275,
+                29.91412429378531,
+                30.9169751454257,
+                31.919642857142858,
+                32.92214452214452,
+                33.92449517120281,
+                34.92670807453416
             ]
         ));
     }
@@ -295,41 +373,41 @@ mod tests {
         assert!(assert_vec_includes_nan(
             q2,
             vec![
-                NAN,
-                NAN,
-                1.5238095238095237,
-                2.5714285714285716,
-                3.6202020202020204,
-                4.662004662004662,
-                5.696703296703297,
-                6.7254901960784315,
-                7.749570003439972,
-                8.769924812030075,
-                9.787314135140223,
-                10.80231884057971,
-                11.815384615384616,
-                12.82685641306331,
-                13.837004078605858,
-                14.846041055718475,
-                15.854138018843901,
-                16.86143286143286,
-                17.868038078564393,
-                18.87404627892433,
-                19.87953434351619,
-                20.884566596194503,
-                21.889197245348956,
-                22.893472282530034,
-                23.897430972388957,
-                24.901107032072627,
-                25.904529572454102,
-                26.907723855092275,
-                27.910711905421064,
-                28.913513012873945,
-                29.91614414142177,
-                30.91862026862027,
-                31.920954667223324,
-                32.92315914035958,
-                33.92524421893681
+                0.0,
+                1.2000000000000004,
+                2.380952380952381,
+                3.5,
+                4.581818181818182,
+                5.641025641025641,
+                6.685714285714286,
+                7.720588235294118,
+                8.748538011695906,
+                9.771428571428572,
+                10.790513833992096,
+                11.806666666666667,
+                12.820512820512821,
+                13.832512315270936,
+                14.843010752688173,
+                15.852272727272727,
+                16.860504201680673,
+                17.86786786786787,
+                18.874493927125506,
+                19.880487804878047,
+                20.8859357696567,
+                21.89090909090909,
+                22.895467160037004,
+                23.89965986394558,
+                24.903529411764705,
+                25.90711175616836,
+                26.910437710437712,
+                27.913533834586467,
+                28.916423144360024,
+                29.91912568306011,
+                30.921658986175114,
+                31.924038461538462,
+                32.92627770239711,
+                33.928388746803066,
+                34.93038229376258
             ]
         ));
     }
@@ -341,7 +419,7 @@ mod tests {
         assert!(assert_vec_includes_nan(
             r1,
             vec![
-                4.0,
+                0.0,
                 3.6,
                 3.8095238095238093,
                 4.166666666666667,
@@ -387,7 +465,7 @@ mod tests {
         assert!(assert_vec_includes_nan(
             r2,
             vec![
-                NAN,
+                2.0000000000000004,
                 2.6666666666666665,
                 3.2142857142857144,
                 3.7333333333333334,
@@ -425,4 +503,245 @@ mod tests {
             ]
         ));
     }
+
+    fn eval(coupling_kind: CouplingKind, branch_kind: DoubletBranchKind, j: f64) -> f64 {
+        HonlLondonFactor {
+            j,
+            lu: 2.0,
+            ll: 1.0,
+            r: 0.0,
+            coupling_kind,
+            branch_kind,
+        }
+        .eval()
+    }
+
+    #[test]
+    fn test_intermediate() {
+        // CH A²Δ (Y' = -0.075) - X²Π (Y'' = 1.97)
+        let c = CouplingKind::Intermediate {
+            yu: -0.075,
+            yl: 1.97,
+        };
+        let expected = [
+            (
+                5.5,
+                [
+                    1.29405415, 5.40655579, 4.8882433, 1.44497116, 5.49388979, 4.70483168,
+                ],
+            ),
+            (
+                10.5,
+                [
+                    3.75946968,
+                    10.68637311,
+                    7.33194853,
+                    3.84884952,
+                    10.71230412,
+                    7.23354262,
+                ],
+            ),
+        ];
+        for (j, e) in expected {
+            for (b, e) in [P1, Q1, R1, P2, Q2, R2].into_iter().zip(e) {
+                assert!((eval(c.clone(), b, j) - e).abs() < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_intermediate_limits() {
+        let b = CouplingKind::Intermediate { yu: 0.0, yl: 0.0 };
+        let a = CouplingKind::Intermediate {
+            yu: 1.0e5,
+            yl: 1.0e5,
+        };
+        for j in (3..35).map(|v| v as f64 + 0.5) {
+            for branch_kind in [P1, Q1, R1, P2, Q2, R2] {
+                let s = eval(CouplingKind::B, branch_kind.clone(), j);
+                assert!((eval(b.clone(), branch_kind.clone(), j) - s).abs() < 1e-12);
+                let s = eval(CouplingKind::A, branch_kind.clone(), j);
+                assert!((eval(a.clone(), branch_kind, j) - s).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_case_a_sum_rule() {
+        for j in (1..35).map(|v| v as f64 + 0.5) {
+            for [p, q, r] in [[P1, Q1, R1], [P2, Q2, R2]] {
+                let s = eval(CouplingKind::A, p, j)
+                    + eval(CouplingKind::A, q, j)
+                    + eval(CouplingKind::A, r, j);
+                assert!((s - (2.0 * j + 1.0)).abs() < 1e-10);
+            }
+        }
+    }
+
+    fn assert_sum_rule(lu: f64, ll: f64, yu: Option<f64>, yl: Option<f64>) {
+        for j in (0..30).map(|v| v as f64 + lu.max(ll) + 1.5) {
+            for k in 0..2 {
+                let s: f64 = (0..2)
+                    .flat_map(|i| [-1.0, 0.0, 1.0].map(|dj| (i, dj)))
+                    .map(|(i, dj)| line_strength(lu, ll, yu, yl, j, dj, i, k))
+                    .sum();
+                assert!(
+                    (s - (2.0 * j + 1.0)).abs() < 1e-9,
+                    "{} {} {:?} {:?} J''={} F{}: {}",
+                    lu,
+                    ll,
+                    yu,
+                    yl,
+                    j,
+                    k + 1,
+                    s
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sum_rule() {
+        assert_sum_rule(0.0, 0.0, Some(0.0), Some(0.0));
+        assert_sum_rule(1.0, 1.0, Some(0.0), Some(0.0));
+        assert_sum_rule(1.0, 1.0, Some(3.0), Some(-1.0));
+        assert_sum_rule(1.0, 0.0, Some(7.5), Some(0.0));
+        assert_sum_rule(0.0, 1.0, Some(0.0), Some(-7.5));
+        assert_sum_rule(1.0, 2.0, Some(1.97), Some(-0.075));
+        assert_sum_rule(1.0, 1.0, None, None);
+        assert_sum_rule(0.0, 1.0, None, None);
+    }
+
+    #[test]
+    fn test_closed_form() {
+        // The closed forms for `ΔΛ = +1` agree with the Hund's case (a) components
+        for (lu, ll, yu, yl) in [(2.0_f64, 1.0, -0.075, 1.97), (1.0, 0.0, 7.5, 0.0)] {
+            for j in (0..30).map(|v| v as f64 + lu.max(ll) + 1.5) {
+                for (i, branch_kind) in [(0, P1), (0, Q1), (0, R1), (1, P2), (1, Q2), (1, R2)] {
+                    let dj = branch_kind.delta_j();
+                    let s = HonlLondonFactor {
+                        j,
+                        lu,
+                        ll,
+                        r: 0.0,
+                        coupling_kind: CouplingKind::Intermediate { yu, yl },
+                        branch_kind,
+                    }
+                    .eval();
+                    assert!(
+                        (s - line_strength(lu, ll, Some(yu), Some(yl), j, dj, i, i)).abs() < 1e-9
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sigma_sigma() {
+        for j in (0..30).map(|v| v as f64 + 0.5) {
+            let p = (j - 0.5) * (j + 0.5) / j;
+            let r = (j + 0.5) * (j + 1.5) / (j + 1.0);
+            for [p1, q1, r1] in [[P1, Q1, R1], [P2, Q2, R2]] {
+                let s = |branch_kind| HonlLondonFactor {
+                    j,
+                    lu: 0.0,
+                    ll: 0.0,
+                    r: 0.0,
+                    coupling_kind: CouplingKind::B,
+                    branch_kind,
+                };
+                assert!((s(p1).eval() - p).abs() < 1e-10);
+                assert!(s(q1).eval().abs() < 1e-10);
+                assert!((s(r1).eval() - r).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sigma_pi() {
+        // OH A²Σ+ - X²Π (Y'' = -7.5) is the reverse of ²Π - ²Σ
+        for j in (0..30).map(|v| v as f64 + 2.5) {
+            for (p, r) in [(P1, R1), (P2, R2)] {
+                let s = HonlLondonFactor {
+                    j,
+                    lu: 0.0,
+                    ll: 1.0,
+                    r: 0.0,
+                    coupling_kind: CouplingKind::Intermediate { yu: 0.0, yl: -7.5 },
+                    branch_kind: p,
+                };
+                let e = HonlLondonFactor {
+                    j: j - 1.0,
+                    lu: 1.0,
+                    ll: 0.0,
+                    r: 0.0,
+                    coupling_kind: CouplingKind::Intermediate { yu: -7.5, yl: 0.0 },
+                    branch_kind: r,
+                };
+                assert!((s.eval() - e.eval()).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pi_pi() {
+        let c = CouplingKind::Intermediate { yu: 3.0, yl: -1.0 };
+        let expected = [
+            5.09824633, 0.35414200, 6.13295844, 5.12447086, 0.29991396, 6.15969506,
+        ];
+        for (branch_kind, e) in [P1, Q1, R1, P2, Q2, R2].into_iter().zip(expected) {
+            let s = HonlLondonFactor {
+                j: 5.5,
+                lu: 1.0,
+                ll: 1.0,
+                r: 0.0,
+                coupling_kind: c.clone(),
+                branch_kind,
+            };
+            assert!((s.eval() - e).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_satellite() {
+        let init = |lu, ll, coupling_kind, branch_kind, j| HonlLondonFactor {
+            j,
+            lu,
+            ll,
+            r: 0.0,
+            coupling_kind,
+            branch_kind,
+        };
+        for j in (1..30).map(|v| v as f64 + 0.5) {
+            // ²Σ-²Σ: only the `ΔJ = 0` satellites appear
+            let q = (2.0 * j + 1.0) / (4.0 * j * (j + 1.0));
+            for (branch_kind, e) in [
+                (O12, 0.0),
+                (P12, q),
+                (Q12, 0.0),
+                (Q21, 0.0),
+                (R21, q),
+                (S21, 0.0),
+            ] {
+                let s = init(0.0, 0.0, CouplingKind::B, branch_kind, j).eval();
+                assert!((s - e).abs() < 1e-10);
+            }
+            // No satellites between ²Π in pure case (a)
+            for branch_kind in [O12, P12, Q12, Q21, R21, S21] {
+                assert_eq!(init(1.0, 1.0, CouplingKind::A, branch_kind, j).eval(), 0.0);
+            }
+        }
+
+        // OH A²Σ+ - X²Π (Y'' = -7.5)
+        let c = CouplingKind::Intermediate { yu: 0.0, yl: -7.5 };
+        for j in (2..30).map(|v| v as f64 + 0.5) {
+            for branches in [[P1, Q1, R1, Q21, R21, S21], [P2, Q2, R2, O12, P12, Q12]] {
+                let s: f64 = branches
+                    .into_iter()
+                    .map(|b| init(0.0, 1.0, c.clone(), b, j).eval())
+                    .sum();
+                assert!((s - (2.0 * j + 1.0)).abs() < 1e-9);
+            }
+        }
+    }
 }
@@ -0,0 +1,361 @@
+use super::{wigner_3j, HonlLondonFactorImpl};
+use crate::prelude::{CouplingKind, EffectiveHamiltonian, MolecularConstants, ParityKind};
+
+/// Hönl-London factor of `Fi'(J''+ΔJ) - Fk''(J'')` for any multiplicity
+///
+/// The levels are the eigenvectors of the rotational Hamiltonian in the Hund's case (a)
+/// basis `|Λ S Σ J Ω⟩` with `Y = A/B`, and the line strength is contracted with
+/// Wigner 3j symbols. This covers any multiplicity, e.g. quartets through
+/// `BranchKind::General`, and is the reference for the closed forms.
+#[derive(Debug, Clone)]
+pub struct HonlLondonFactor {
+    /// `J''`: Quantum number of rotation
+    pub j: f64,
+    /// `S`: Spin quantum number
+    pub s: f64,
+    /// `Λ'`: Electronic state
+    pub lu: f64,
+    /// `Λ''`: Electronic state
+    pub ll: f64,
+    pub coupling_kind: CouplingKind,
+    /// `i` of the upper level `Fi'` (`1..=2S+1`)
+    pub fu: usize,
+    /// `k` of the lower level `Fk''` (`1..=2S+1`)
+    pub fl: usize,
+    /// `ΔJ = J' - J''`
+    pub dj: f64,
+}
+
+impl HonlLondonFactorImpl for HonlLondonFactor {
+    /// Returns the line strength normalized as `Σ S = 2J''+1` over all the branches
+    /// (including the satellites) from a lower level.
+    fn eval(self) -> f64 {
+        let Self {
+            j,
+            s,
+            lu,
+            ll,
+            coupling_kind,
+            fu,
+            fl,
+            dj,
+        } = self;
+
+        let q = lu - ll;
+        if q.abs() > 1.0 || j + dj < 0.0 || fu == 0 || fl == 0 {
+            return 0.0;
+        }
+
+        let (yu, yl) = match coupling_kind {
+            CouplingKind::A => (None, None),
+            CouplingKind::B => (Some(0.0), Some(0.0)),
+            CouplingKind::Intermediate { yu, yl } => (Some(yu), Some(yl)),
+        };
+
+        let cu = components(s, lu, yu, j + dj);
+        let cl = components(s, ll, yl, j);
+        match (cu.get(fu - 1), cl.get(fl - 1)) {
+            (Some(Some(cu)), Some(Some(cl))) => line_strength(j, s, lu, ll, dj, cu, cl),
+            _ => 0.0,
+        }
+    }
+}
+
+/// Returns the Hund's case (a) components `[Σ=-S, ..., Σ=S]` of `F1`, ..., `F(2S+1)`,
+/// or `None` for the levels which do not exist at `J`.
+///
+/// The levels of `Λ > 0` are the eigenvectors of `EffectiveHamiltonian` with `B = 1` and
+/// `A = Y`, in pure case (a) when `y` is `None`, and `Σ` levels are always in case (b).
+/// When some levels are missing at low `J`, the highest `Fi` are missing if `Y > 2` and
+/// the lowest `Fi` otherwise.
+pub(super) fn components(s: f64, l: f64, y: Option<f64>, j: f64) -> Vec<Option<Vec<f64>>> {
+    if let (None, true) = (y, l > 0.0) {
+        let m = (2.0 * s).round() as usize + 1;
+        return (0..m)
+            .map(|k| {
+                ((l + k as f64 - s).abs() <= j).then(|| {
+                    let mut v = vec![0.0; m];
+                    v[k] = 1.0;
+                    v
+                })
+            })
+            .collect();
+    }
+
+    let constants = MolecularConstants {
+        b: 1.0,
+        a: y.unwrap_or(0.0),
+        ..Default::default()
+    };
+    EffectiveHamiltonian::new(s, l, j, constants, ParityKind::E)
+        .eval()
+        .into_iter()
+        .map(|level| level.map(|(_, v)| v))
+        .collect()
+}
+
+/// Returns the line strength between the levels with the Hund's case (a) components
+/// `[Σ=-S, ..., Σ=S]` (e.g. the eigenvectors of `EffectiveHamiltonian`), normalized as
+/// `Σ S = 2J''+1` over all the branches from a lower level
+///
+/// # Arguments
+///
+/// * `j` - `J''`: Quantum number of rotation
+/// * `s` - `S`: Spin quantum number
+/// * `lu` - `Λ'`: Electronic state
+/// * `ll` - `Λ''`: Electronic state
+/// * `dj` - `ΔJ = J' - J''`
+/// * `cu` - Components of the upper level
+/// * `cl` - Components of the lower level
+pub fn line_strength(j: f64, s: f64, lu: f64, ll: f64, dj: f64, cu: &[f64], cl: &[f64]) -> f64 {
+    let q = lu - ll;
+    if q.abs() > 1.0 || j + dj < 0.0 {
+        return 0.0;
+    }
+
+    // √((2J''+1)(2J'+1)) (-1)^(J''-1+Ω') (J'' 1 J'; Ω'' q -Ω')
+    let jj = j + dj;
+    let dc = |omega: f64| {
+        let phase = if (j - 1.0 + omega + q).round() as i64 % 2 == 0 {
+            1.0
+        } else {
+            -1.0
+        };
+        ((2.0 * j + 1.0) * (2.0 * jj + 1.0)).sqrt()
+            * phase
+            * wigner_3j(j, 1.0, jj, omega, q, -omega - q)
+    };
+    cu.iter()
+        .zip(cl.iter())
+        .enumerate()
+        .map(|(k, (a, b))| a * b * dc(ll + k as f64 - s))
+        .sum::<f64>()
+        .powi(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::{CouplingKind, DoubletBranchKind, TripletBranchKind},
+        usecase::honl_london_factor::{doublet, singlet, triplet, HonlLondonFactorImpl},
+    };
+
+    use super::HonlLondonFactor;
+
+    #[allow(clippy::too_many_arguments)]
+    fn init(
+        s: f64,
+        lu: f64,
+        ll: f64,
+        coupling_kind: CouplingKind,
+        fu: usize,
+        fl: usize,
+        dj: f64,
+        j: f64,
+    ) -> f64 {
+        HonlLondonFactor {
+            j,
+            s,
+            lu,
+            ll,
+            coupling_kind,
+            fu,
+            fl,
+            dj,
+        }
+        .eval()
+    }
+
+    fn assert_sum_rule(s: f64, lu: f64, ll: f64, coupling_kind: CouplingKind) {
+        let m = (2.0 * s).round() as usize + 1;
+        for j in (0..25).map(|v| v as f64 + lu.max(ll) + s + 1.0) {
+            for fl in 1..=m {
+                let sum: f64 = (1..=m)
+                    .flat_map(|fu| [-1.0, 0.0, 1.0].map(|dj| (fu, dj)))
+                    .map(|(fu, dj)| init(s, lu, ll, coupling_kind.clone(), fu, fl, dj, j))
+                    .sum();
+                assert!(
+                    (sum - (2.0 * j + 1.0)).abs() < 1e-9,
+                    "S={} {} {} {:?} J''={} F{}: {}",
+                    s,
+                    lu,
+                    ll,
+                    coupling_kind,
+                    j,
+                    fl,
+                    sum
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sum_rule() {
+        let c = |yu, yl| CouplingKind::Intermediate { yu, yl };
+        for s in [0.0, 0.5, 1.0, 1.5, 2.0] {
+            assert_sum_rule(s, 0.0, 0.0, CouplingKind::B);
+            assert_sum_rule(s, 1.0, 1.0, CouplingKind::A);
+            assert_sum_rule(s, 1.0, 1.0, c(5.0, -3.0));
+            assert_sum_rule(s, 1.0, 0.0, c(20.0, 0.0));
+            assert_sum_rule(s, 0.0, 1.0, c(0.0, 1.5));
+            assert_sum_rule(s, 2.0, 1.0, c(-0.5, 8.0));
+        }
+    }
+
+    #[test]
+    fn singlet() {
+        use crate::prelude::SingletBranchKind::*;
+
+        for (lu, ll) in [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0), (2.0, 1.0)] {
+            for j in (1..30).map(|v| v as f64) {
+                for branch_kind in [P1, Q1, R1] {
+                    let dj = branch_kind.delta_j();
+                    let e = singlet::HonlLondonFactor {
+                        j,
+                        lu,
+                        ll,
+                        r: 0.0,
+                        coupling_kind: CouplingKind::B,
+                        branch_kind,
+                    }
+                    .eval();
+                    let s = init(0.0, lu, ll, CouplingKind::B, 1, 1, dj, j);
+                    assert!((s - e).abs() < 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn doublet() {
+        use DoubletBranchKind::*;
+
+        let branches = [
+            (P1, 1, 1),
+            (Q1, 1, 1),
+            (R1, 1, 1),
+            (P2, 2, 2),
+            (Q2, 2, 2),
+            (R2, 2, 2),
+            (O12, 1, 2),
+            (P12, 1, 2),
+            (Q12, 1, 2),
+            (Q21, 2, 1),
+            (R21, 2, 1),
+            (S21, 2, 1),
+        ];
+        let c = |yu, yl| CouplingKind::Intermediate { yu, yl };
+        for (lu, ll, coupling_kind) in [
+            (2.0_f64, 1.0, c(-0.075, 1.97)),
+            (1.0, 0.0, c(7.5, 0.0)),
+            (0.0, 1.0, c(0.0, -7.5)),
+            (1.0, 1.0, c(3.0, -1.0)),
+            (0.0, 0.0, CouplingKind::B),
+            (1.0, 1.0, CouplingKind::A),
+        ] {
+            for j in (0..30).map(|v| v as f64 + lu.max(ll) + 1.5) {
+                for (branch_kind, fu, fl) in branches.clone() {
+                    let dj = branch_kind.delta_j();
+                    let e = doublet::HonlLondonFactor {
+                        j,
+                        lu,
+                        ll,
+                        r: 0.0,
+                        coupling_kind: coupling_kind.clone(),
+                        branch_kind,
+                    }
+                    .eval();
+                    let s = init(0.5, lu, ll, coupling_kind.clone(), fu, fl, dj, j);
+                    assert!((s - e).abs() < 1e-9, "{} {} {} {}", j, fu, fl, dj);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn triplet() {
+        use TripletBranchKind::*;
+
+        let branches = [
+            (P1, 1),
+            (Q1, 1),
+            (R1, 1),
+            (P2, 2),
+            (Q2, 2),
+            (R2, 2),
+            (P3, 3),
+            (Q3, 3),
+            (R3, 3),
+        ];
+        let c = CouplingKind::Intermediate { yu: 21.5, yl: 25.9 };
+        for j in (2..30).map(|v| v as f64) {
+            for (branch_kind, f) in branches.clone() {
+                let dj = branch_kind.delta_j();
+                let e = triplet::HonlLondonFactor {
+                    j,
+                    lu: 1.0,
+                    ll: 1.0,
+                    r: 0.0,
+                    coupling_kind: c.clone(),
+                    branch_kind,
+                }
+                .eval();
+                let s = init(1.0, 1.0, 1.0, c.clone(), f, f, dj, j);
+                assert!((s - e).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn quartet() {
+        // ⁴Σ-⁴Σ in case (b): P and R of F1, ..., F4
+        let expected = [
+            10.4210526316,
+            11.4285714286,
+            10.3265306122,
+            11.3421550095,
+            10.3401360544,
+            11.3534971645,
+            10.4347826087,
+            11.44,
+        ];
+        let res = (1..=4).flat_map(|f| {
+            [-1.0, 1.0].map(|dj| init(1.5, 0.0, 0.0, CouplingKind::B, f, f, dj, 10.5))
+        });
+        for (r, e) in res.zip(expected) {
+            assert!((r - e).abs() < 1e-9);
+        }
+
+        // O₂⁺ b⁴Σ- - a⁴Π (Y'' = 48): P, Q and R of F1-F1, ..., F4-F4, F1-F2 and F2-F1
+        let c = CouplingKind::Intermediate { yu: 0.0, yl: 48.0 };
+        let expected = [
+            1.7573021957,
+            4.0331510785,
+            2.2876070456,
+            0.0849310474,
+            0.1613473004,
+            0.0820254432,
+            0.0863097901,
+            0.1530815166,
+            0.0730950469,
+            2.4390818747,
+            3.9403342855,
+            1.6218674461,
+            2.4943394012,
+            4.6553032856,
+            2.1706110166,
+            1.9432537269,
+            4.8873511133,
+            2.9902088796,
+        ];
+        let res = [(1, 1), (2, 2), (3, 3), (4, 4), (1, 2), (2, 1)]
+            .into_iter()
+            .flat_map(|(fu, fl)| {
+                [-1.0, 0.0, 1.0].map(|dj| init(1.5, 0.0, 1.0, c.clone(), fu, fl, dj, 10.5))
+            });
+        for (r, e) in res.zip(expected) {
+            assert!((r - e).abs() < 1e-9);
+        }
+        assert_sum_rule(1.5, 0.0, 1.0, c);
+    }
+}
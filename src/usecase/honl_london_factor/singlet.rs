@@ -1,32 +1,116 @@
-use super::HonlLondonFactorImpl;
-#[allow(unused)]
-use crate::prelude::{
-    CouplingKind,
-    SingletBranchKind::{self, *},
-};
+use super::{direction_cosine, HonlLondonFactorImpl};
+use crate::prelude::{CouplingKind, SingletBranchKind};
 
 #[derive(Debug, Clone)]
 pub struct HonlLondonFactor {
-    /// `J'`: Quantum number of rotation
-    #[allow(unused)]
-    j: f64,
+    /// `J''`: Quantum number of rotation
+    pub j: f64,
     /// `Λ'`: Electronic state
-    #[allow(unused)]
-    lu: f64,
+    pub lu: f64,
     /// `Λ''`: Electronic state
-    #[allow(unused)]
-    ll: f64,
+    pub ll: f64,
     /// `γ`: Rotation-vibration interaction constant
-    #[allow(unused)]
-    r: f64,
-    #[allow(unused)]
-    coupling_kind: CouplingKind,
-    #[allow(unused)]
-    branch_kind: SingletBranchKind,
+    pub r: f64,
+    pub coupling_kind: CouplingKind,
+    pub branch_kind: SingletBranchKind,
 }
 
 impl HonlLondonFactorImpl for HonlLondonFactor {
+    /// Returns the line strength normalized as `Σ S = 2J''+1` over the branches.
+    /// Transitions with `|ΔΛ| > 1` are forbidden and returns `0`.
     fn eval(self) -> f64 {
-        unimplemented!()
+        let Self {
+            j,
+            lu,
+            ll,
+            branch_kind,
+            ..
+        } = self;
+
+        let dj = branch_kind.delta_j();
+
+        if (lu - ll).abs() > 1.0 {
+            return 0.0;
+        }
+
+        direction_cosine(j, ll, lu - ll, dj).powi(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::{
+            CouplingKind,
+            SingletBranchKind::{self, *},
+        },
+        usecase::honl_london_factor::HonlLondonFactorImpl,
+    };
+
+    use super::HonlLondonFactor;
+
+    fn init(lu: f64, ll: f64, branch_kind: SingletBranchKind, j: f64) -> f64 {
+        HonlLondonFactor {
+            j,
+            lu,
+            ll,
+            r: 0.0,
+            coupling_kind: CouplingKind::B,
+            branch_kind,
+        }
+        .eval()
+    }
+
+    fn assert_sum_rule(lu: f64, ll: f64) {
+        for j in (0..40).map(|v| v as f64 + ll) {
+            let s = init(lu, ll, P1, j) + init(lu, ll, Q1, j) + init(lu, ll, R1, j);
+            assert!((s - (2.0 * j + 1.0)).abs() < 1e-10, "J''={}: {}", j, s);
+        }
+    }
+
+    #[test]
+    fn sum_rule() {
+        assert_sum_rule(0.0, 0.0);
+        assert_sum_rule(1.0, 1.0);
+        assert_sum_rule(1.0, 0.0);
+        assert_sum_rule(0.0, 1.0);
+        assert_sum_rule(2.0, 1.0);
+    }
+
+    #[test]
+    fn test_sigma_sigma() {
+        for j in (0..20).map(|v| v as f64) {
+            assert!((init(0.0, 0.0, P1, j) - j).abs() < 1e-10);
+            assert_eq!(init(0.0, 0.0, Q1, j), 0.0);
+            assert!((init(0.0, 0.0, R1, j) - (j + 1.0)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_pi_sigma() {
+        for j in (1..20).map(|v| v as f64) {
+            assert!((init(1.0, 0.0, P1, j) - (j - 1.0) / 2.0).abs() < 1e-10);
+            assert!((init(1.0, 0.0, Q1, j) - (2.0 * j + 1.0) / 2.0).abs() < 1e-10);
+            assert!((init(1.0, 0.0, R1, j) - (j + 2.0) / 2.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_pi_pi() {
+        for j in (1..20).map(|v| v as f64) {
+            let p = (j - 1.0) * (j + 1.0) / j;
+            let q = (2.0 * j + 1.0) / (j * (j + 1.0));
+            let r = j * (j + 2.0) / (j + 1.0);
+            assert!((init(1.0, 1.0, P1, j) - p).abs() < 1e-10);
+            assert!((init(1.0, 1.0, Q1, j) - q).abs() < 1e-10);
+            assert!((init(1.0, 1.0, R1, j) - r).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_forbidden() {
+        assert_eq!(init(2.0, 0.0, R1, 3.0), 0.0);
+        assert_eq!(init(1.0, 0.0, P1, 0.0), 0.0);
+        assert_eq!(init(0.0, 1.0, R1, 0.0), 0.0);
     }
 }
@@ -1,5 +1,4 @@
-use super::HonlLondonFactorImpl;
-#[allow(unused)]
+use super::{direction_cosine, general::components, HonlLondonFactorImpl};
 use crate::prelude::{
     CouplingKind,
     TripletBranchKind::{self, *},
@@ -7,26 +6,185 @@ use crate::prelude::{
 
 #[derive(Debug, Clone)]
 pub struct HonlLondonFactor {
-    /// `J'`: Quantum number of rotation
-    #[allow(unused)]
-    j: f64,
+    /// `J''`: Quantum number of rotation
+    pub j: f64,
     /// `Λ'`: Electronic state
-    #[allow(unused)]
-    lu: f64,
+    pub lu: f64,
     /// `Λ''`: Electronic state
-    #[allow(unused)]
-    ll: f64,
+    pub ll: f64,
     /// `γ`: Rotation-vibration interaction constant
-    #[allow(unused)]
-    r: f64,
-    #[allow(unused)]
-    coupling_kind: CouplingKind,
-    #[allow(unused)]
-    branch_kind: TripletBranchKind,
+    pub r: f64,
+    pub coupling_kind: CouplingKind,
+    pub branch_kind: TripletBranchKind,
 }
 
 impl HonlLondonFactorImpl for HonlLondonFactor {
+    /// Returns the line strength normalized as `Σ S = 2J''+1` over all the branches
+    /// (including the satellites) from a lower level.
     fn eval(self) -> f64 {
-        unimplemented!()
+        let Self {
+            j,
+            lu,
+            ll,
+            coupling_kind,
+            branch_kind,
+            ..
+        } = self;
+
+        let i = match branch_kind {
+            P1 | Q1 | R1 => 0,
+            P2 | Q2 | R2 => 1,
+            P3 | Q3 | R3 => 2,
+        };
+
+        let (yu, yl) = match coupling_kind {
+            CouplingKind::A => (None, None),
+            CouplingKind::B => (Some(0.0), Some(0.0)),
+            CouplingKind::Intermediate { yu, yl } => (Some(yu), Some(yl)),
+        };
+
+        line_strength(lu, ll, yu, yl, j, branch_kind.delta_j(), i, i)
+    }
+}
+
+/// Returns the line strength of `Fi'(J''+dj) - Fk''(J'')`
+#[allow(clippy::too_many_arguments)]
+fn line_strength(
+    lu: f64,
+    ll: f64,
+    yu: Option<f64>,
+    yl: Option<f64>,
+    j: f64,
+    dj: f64,
+    i: usize,
+    k: usize,
+) -> f64 {
+    let q = lu - ll;
+    if q.abs() > 1.0 || j + dj < 0.0 {
+        return 0.0;
+    }
+
+    match (
+        &components(1.0, lu, yu, j + dj)[i],
+        &components(1.0, ll, yl, j)[k],
+    ) {
+        (Some(cu), Some(cl)) => (0..3)
+            .map(|s| cu[s] * cl[s] * direction_cosine(j, ll + s as f64 - 1.0, q, dj))
+            .sum::<f64>()
+            .powi(2),
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::{
+            CouplingKind,
+            TripletBranchKind::{self, *},
+        },
+        usecase::honl_london_factor::HonlLondonFactorImpl,
+    };
+
+    use super::{line_strength, HonlLondonFactor};
+
+    fn init(
+        lu: f64,
+        ll: f64,
+        coupling_kind: CouplingKind,
+        branch_kind: TripletBranchKind,
+        j: f64,
+    ) -> f64 {
+        HonlLondonFactor {
+            j,
+            lu,
+            ll,
+            r: 0.0,
+            coupling_kind,
+            branch_kind,
+        }
+        .eval()
+    }
+
+    fn assert_sum_rule(lu: f64, ll: f64, yu: Option<f64>, yl: Option<f64>) {
+        for j in (0..30).map(|v| v as f64) {
+            for k in 0..3 {
+                let s: f64 = (0..3)
+                    .flat_map(|i| [-1.0, 0.0, 1.0].map(|dj| (i, dj)))
+                    .map(|(i, dj)| line_strength(lu, ll, yu, yl, j, dj, i, k))
+                    .sum();
+                let exists = (0..3)
+                    .flat_map(|i| [-1.0, 0.0, 1.0].map(|dj| (i, dj)))
+                    .any(|(i, dj)| line_strength(lu, ll, yu, yl, j, dj, i, k) > 0.0);
+                if exists {
+                    assert!(
+                        (s - (2.0 * j + 1.0)).abs() < 1e-9,
+                        "{} {} {:?} {:?} J''={} F{}: {}",
+                        lu,
+                        ll,
+                        yu,
+                        yl,
+                        j,
+                        k + 1,
+                        s
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sum_rule() {
+        assert_sum_rule(0.0, 0.0, Some(0.0), Some(0.0));
+        assert_sum_rule(1.0, 1.0, Some(0.0), Some(0.0));
+        assert_sum_rule(1.0, 1.0, Some(21.5), Some(25.9));
+        assert_sum_rule(1.0, 1.0, Some(-3.0), Some(1.5));
+        assert_sum_rule(1.0, 0.0, Some(25.9), Some(0.0));
+        assert_sum_rule(0.0, 1.0, Some(0.0), Some(-7.0));
+        assert_sum_rule(2.0, 1.0, Some(4.0), Some(3.0));
+        assert_sum_rule(1.0, 1.0, None, None);
+    }
+
+    #[test]
+    fn case_a() {
+        // Pure case (a) reduces to the Ω sub-bands: ³Π0-³Π0, ³Π1-³Π1 and ³Π2-³Π2
+        for j in (2..30).map(|v| v as f64) {
+            for (omega, p, q, r) in [(0.0, P1, Q1, R1), (1.0, P2, Q2, R2), (2.0, P3, Q3, R3)] {
+                let ep = (j - omega) * (j + omega) / j;
+                let eq = omega.powi(2) * (2.0 * j + 1.0) / (j * (j + 1.0));
+                let er = (j + 1.0 - omega) * (j + 1.0 + omega) / (j + 1.0);
+                assert!((init(1.0, 1.0, CouplingKind::A, p, j) - ep).abs() < 1e-10);
+                assert!((init(1.0, 1.0, CouplingKind::A, q, j) - eq).abs() < 1e-10);
+                assert!((init(1.0, 1.0, CouplingKind::A, r, j) - er).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn case_b_sigma_sigma() {
+        // ³Σ-³Σ in case (b), N'' = J''-1 for F1 and N'' = J''+1 for F3
+        for n in (2..30).map(|v| v as f64) {
+            let r1 = init(0.0, 0.0, CouplingKind::B, R1, n + 1.0);
+            let p3 = init(0.0, 0.0, CouplingKind::B, P3, n - 1.0);
+            assert!((r1 - (n + 1.0) * (2.0 * n + 5.0) / (2.0 * n + 3.0)).abs() < 1e-10);
+            assert!((p3 - n * (2.0 * n - 3.0) / (2.0 * n - 1.0)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn intermediate() {
+        // N2 second positive system, C³Π (Y' = 21.5) - B³Π (Y'' = 25.9)
+        let c = CouplingKind::Intermediate { yu: 21.5, yl: 25.9 };
+        let res: Vec<f64> = [P1, Q1, R1, P2, Q2, R2, P3, Q3, R3]
+            .into_iter()
+            .map(|b| init(1.0, 1.0, c.clone(), b, 10.0))
+            .collect();
+        let expected = [
+            9.962465, 0.012686, 10.859109, 9.818211, 0.206692, 10.621488, 9.666112, 0.545839,
+            10.598028,
+        ];
+        for (r, e) in res.iter().zip(expected) {
+            assert!((r - e).abs() < 1e-6, "{:?}", res);
+        }
     }
 }
@@ -0,0 +1,116 @@
+type Matrix = Vec<Vec<f64>>;
+
+/// Cyclic Jacobi eigenvalue algorithm for real symmetric matrices
+#[derive(Debug, Clone)]
+pub struct Jacobi {
+    matrix: Matrix,
+}
+
+impl Jacobi {
+    /// Returns the new Jacobi instance
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - Real symmetric matrix (`n` rows of `n` elements)
+    pub fn new(matrix: Matrix) -> Self {
+        Self { matrix }
+    }
+
+    /// Returns the eigenvalues and the normalized eigenvectors in ascending order of
+    /// the eigenvalue, consuming `self` value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::usecase::jacobi::Jacobi;
+    /// let res = Jacobi::new(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).eval();
+    /// assert!((res[0].0 - 1.0).abs() < 1e-12);
+    /// assert!((res[1].0 - 3.0).abs() < 1e-12);
+    /// assert!((res[1].1[0] - res[1].1[1]).abs() < 1e-12);
+    /// ```
+    pub fn eval(self) -> Vec<(f64, Vec<f64>)> {
+        let mut a = self.matrix;
+        let n = a.len();
+        let mut v: Matrix = (0..n)
+            .map(|i| (0..n).map(|k| if i == k { 1.0 } else { 0.0 }).collect())
+            .collect();
+
+        for _ in 0..100 {
+            let off: f64 = (0..n)
+                .flat_map(|i| (0..n).filter(move |&k| k != i).map(move |k| (i, k)))
+                .map(|(i, k)| a[i][k].powi(2))
+                .sum();
+            let norm: f64 = a.iter().flatten().map(|x| x.powi(2)).sum();
+            if off <= f64::EPSILON.powi(2) * norm {
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if a[p][q] == 0.0 {
+                        continue;
+                    }
+                    let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta.powi(2) + 1.0).sqrt());
+                    let c = 1.0 / (t.powi(2) + 1.0).sqrt();
+                    let s = t * c;
+
+                    for row in a.iter_mut() {
+                        let (akp, akq) = (row[p], row[q]);
+                        row[p] = c * akp - s * akq;
+                        row[q] = s * akp + c * akq;
+                    }
+                    let (upper, lower) = a.split_at_mut(q);
+                    for (apk, aqk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                        let (x, y) = (*apk, *aqk);
+                        *apk = c * x - s * y;
+                        *aqk = s * x + c * y;
+                    }
+                    for row in v.iter_mut() {
+                        let (vkp, vkq) = (row[p], row[q]);
+                        row[p] = c * vkp - s * vkq;
+                        row[q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        let mut res: Vec<(f64, Vec<f64>)> = (0..n)
+            .map(|i| (a[i][i], v.iter().map(|row| row[i]).collect()))
+            .collect();
+        res.sort_by(|(x, _), (y, _)| x.total_cmp(y));
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Jacobi;
+
+    #[test]
+    fn test() {
+        let m = vec![
+            vec![4.0, -2.0, 0.5],
+            vec![-2.0, 1.0, 3.0],
+            vec![0.5, 3.0, -1.0],
+        ];
+        let res = Jacobi::new(m.clone()).eval();
+
+        assert!(res.windows(2).all(|w| w[0].0 <= w[1].0));
+        for (e, v) in res {
+            let norm: f64 = v.iter().map(|x| x.powi(2)).sum();
+            assert!((norm - 1.0).abs() < 1e-12);
+            for (row, &vi) in m.iter().zip(v.iter()) {
+                let mv: f64 = row.iter().zip(v.iter()).map(|(a, b)| a * b).sum();
+                assert!((mv - e * vi).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn diagonal() {
+        let res = Jacobi::new(vec![vec![3.0, 0.0], vec![0.0, -1.0]]).eval();
+        assert_eq!(res[0], (-1.0, vec![0.0, 1.0]));
+        assert_eq!(res[1], (3.0, vec![1.0, 0.0]));
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{
+    prelude::{
+        ElectronicState, ParityKind, RotationalDistributionKind, Term, VibrationalDistributionKind,
+    },
+    usecase::electronic_state::boltzmann,
+};
+
+/// Population of a level
+#[derive(Debug, Clone)]
+pub struct Population {
+    /// Index of the electronic state in `Populations::electronic_states`
+    pub state: usize,
+    /// Vibrational level
+    pub v: u8,
+    /// `J`: Rotational level
+    pub j: f64,
+    /// `i` of `Fi`
+    pub i: usize,
+    /// Parity of the level
+    pub parity_kind: ParityKind,
+    /// `Te + G(v) + Fi(J)`: Term value (`cm^-1`)
+    pub term: Term,
+    /// Fraction of the molecules in the level
+    pub fraction: f64,
+}
+
+/// Populations of the levels of electronic states
+///
+/// The fraction of a level is
+/// `ge exp(-Te/Tel)/Qel · N(v)/Qv · N(J)/N`, with `N(v)` of `vibrational_distribution_kind`
+/// and `Qv` of the electronic state, and `N(J)/N` of `rotational_distribution_kind` in the
+/// vibrational level.
+#[derive(Debug, Clone)]
+pub struct Populations {
+    pub electronic_states: Vec<ElectronicState>,
+    /// `Tel`: Electronic temperature (eV)
+    pub t_el: Term,
+    /// `Tv`: Vibrational temperature (eV)
+    pub t_v: Term,
+    /// Model of the vibrational populations
+    pub vibrational_distribution_kind: VibrationalDistributionKind,
+    /// `Tr`: Rotational temperature (eV)
+    pub t_r: Term,
+    /// Model of the rotational populations
+    pub rotational_distribution_kind: RotationalDistributionKind,
+    /// Highest vibrational level of the electronic states
+    pub v_max: u8,
+}
+
+impl Populations {
+    /// Returns `Qel = Σ ge exp(-Te/Tel)` over `electronic_states`
+    pub fn electronic_partition_function(&self) -> f64 {
+        self.electronic_states
+            .iter()
+            .map(|state| state.g * boltzmann(state.t_e, self.t_el))
+            .sum()
+    }
+
+    /// Returns the populations of all the levels up to `v_max`, whose fractions sum to `1`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::*;
+    /// let state = ElectronicState {
+    ///     spin_quantum_number_kind: SpinQuantumNumberKind::Singlet,
+    ///     l: 0.0,
+    ///     r: 0.0,
+    ///     symmetry_kind: SymmetryKind::Plus,
+    ///     dunham_coefficients: DunhamCoefficients::new().with(1, 0, 2000.0).with(0, 1, 2.0),
+    ///     rotational_term_kind: RotationalTermKind::Dunham,
+    ///     t_e: Term::new(0.0),
+    ///     g: 1.0,
+    ///     nuclear_spin_kind: NuclearSpinKind::Heteronuclear,
+    /// };
+    /// let populations = Populations {
+    ///     electronic_states: vec![state],
+    ///     t_el: Term::new(0.5),
+    ///     t_v: Term::new(0.5),
+    ///     vibrational_distribution_kind: VibrationalDistributionKind::Boltzmann,
+    ///     t_r: Term::new(0.05),
+    ///     rotational_distribution_kind: RotationalDistributionKind::Boltzmann,
+    ///     v_max: 3,
+    /// };
+    /// let res = populations.eval();
+    /// let sum: f64 = res.iter().map(|p| p.fraction).sum();
+    /// assert!((sum - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn eval(&self) -> Vec<Population> {
+        let q_el = self.electronic_partition_function();
+        self.electronic_states
+            .iter()
+            .enumerate()
+            .flat_map(|(n, state)| {
+                let f_el = state.g * boltzmann(state.t_e, self.t_el) / q_el;
+                let q_v = state.vibrational_partition_function(
+                    self.v_max,
+                    self.t_v,
+                    &self.vibrational_distribution_kind,
+                );
+                (0..=self.v_max).flat_map(move |v| {
+                    let e_v = state.vibrational_term(v);
+                    let f_v =
+                        f_el * state.vibrational_population(
+                            v,
+                            self.t_v,
+                            &self.vibrational_distribution_kind,
+                        ) / q_v;
+                    state
+                        .rotational_populations(v, self.t_r, &self.rotational_distribution_kind)
+                        .into_iter()
+                        .map(move |((j, i, parity_kind, e_r), f_r)| Population {
+                            state: n,
+                            v,
+                            j,
+                            i,
+                            parity_kind,
+                            term: state.t_e + e_v + e_r,
+                            fraction: f_v * f_r,
+                        })
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::constant::{C, E, H},
+        prelude::*,
+    };
+
+    fn state(
+        spin_quantum_number_kind: SpinQuantumNumberKind,
+        l: f64,
+        t_e: f64,
+        g: f64,
+    ) -> ElectronicState {
+        ElectronicState {
+            spin_quantum_number_kind,
+            l,
+            r: 0.0,
+            symmetry_kind: SymmetryKind::Plus,
+            dunham_coefficients: SpectroscopicConstants {
+                we: 2000.0,
+                wexe: 10.0,
+                be: 2.0,
+                alpha_e: 0.02,
+                ..Default::default()
+            }
+            .into(),
+            rotational_term_kind: RotationalTermKind::Dunham,
+            t_e: Term::new(t_e),
+            g,
+            nuclear_spin_kind: NuclearSpinKind::Heteronuclear,
+        }
+    }
+
+    #[test]
+    fn populations() {
+        let populations = Populations {
+            electronic_states: vec![
+                state(SpinQuantumNumberKind::Singlet, 0.0, 0.0, 1.0),
+                state(SpinQuantumNumberKind::Triplet, 1.0, 10000.0, 6.0),
+            ],
+            t_el: Term::new(1.0),
+            t_v: Term::new(0.3),
+            vibrational_distribution_kind: VibrationalDistributionKind::Boltzmann,
+            t_r: Term::new(0.03),
+            rotational_distribution_kind: RotationalDistributionKind::Boltzmann,
+            v_max: 5,
+        };
+        let res = populations.eval();
+        let sum: f64 = res.iter().map(|p| p.fraction).sum();
+        assert!((sum - 1.0).abs() < 1e-9);
+
+        // Electronic states: `ge exp(-Te/Tel)`
+        let kt = |t: f64| E * t / (H * C * 100.0);
+        let f = |n: usize| -> f64 {
+            res.iter()
+                .filter(|p| p.state == n)
+                .map(|p| p.fraction)
+                .sum()
+        };
+        assert!((f(1) / f(0) - 6.0 * (-10000.0 / kt(1.0)).exp()).abs() < 1e-9);
+
+        // The triplet Π has `3 × 2` levels of each `J` with `Fi(J)` of the Dunham expansion
+        let levels = |n: usize, v: u8, j: f64| -> Vec<&Population> {
+            res.iter()
+                .filter(|p| (p.state, p.v, p.j) == (n, v, j))
+                .collect()
+        };
+        assert_eq!(levels(0, 0, 3.0).len(), 1);
+        assert_eq!(levels(1, 0, 3.0).len(), 6);
+        // Only `Ω = 0` at `J = 0` and `Ω = 0, 1` at `J = 1`
+        assert_eq!(levels(1, 0, 0.0).len(), 2);
+        assert_eq!(levels(1, 0, 1.0).len(), 4);
+
+        // Rotational levels: `(2J+1) exp(-F(J)/Tr)`
+        let (p0, p5) = (levels(0, 1, 0.0)[0], levels(0, 1, 5.0)[0]);
+        let e = (p5.term - p0.term).unwrap();
+        assert!((p5.fraction / p0.fraction - 11.0 * (-e / kt(0.03)).exp()).abs() < 1e-9);
+
+        // Vibrational levels: `exp(-G(v)/Tv)`
+        let fv = |v: u8| -> f64 {
+            res.iter()
+                .filter(|p| (p.state, p.v) == (0, v))
+                .map(|p| p.fraction)
+                .sum()
+        };
+        let e = (levels(0, 2, 0.0)[0].term - levels(0, 0, 0.0)[0].term).unwrap();
+        assert!((fv(2) / fv(0) - (-e / kt(0.3)).exp()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn vibrational_distributions() {
+        let mut populations = Populations {
+            electronic_states: vec![state(SpinQuantumNumberKind::Singlet, 0.0, 0.0, 1.0)],
+            t_el: Term::new(1.0),
+            t_v: Term::new(0.3),
+            vibrational_distribution_kind: VibrationalDistributionKind::Table(vec![
+                (0, 4.0),
+                (1, 2.0),
+                (3, 2.0),
+            ]),
+            t_r: Term::new(0.03),
+            rotational_distribution_kind: RotationalDistributionKind::Boltzmann,
+            v_max: 5,
+        };
+        let fv = |res: &[Population], v: u8| -> f64 {
+            res.iter().filter(|p| p.v == v).map(|p| p.fraction).sum()
+        };
+
+        // `N(v)` of the table, `0` for the levels not in the table
+        let res = populations.eval();
+        for (v, n) in [(0, 0.5), (1, 0.25), (2, 0.0), (3, 0.25), (4, 0.0), (5, 0.0)] {
+            assert!((fv(&res, v) - n).abs() < 1e-9);
+        }
+
+        // Treanor overpopulates the high levels compared to Boltzmann at the same `Tv`
+        populations.vibrational_distribution_kind = VibrationalDistributionKind::Boltzmann;
+        let boltzmann = populations.eval();
+        populations.vibrational_distribution_kind =
+            VibrationalDistributionKind::Treanor { t: Term::new(0.05) };
+        let treanor = populations.eval();
+        let ratio = |res: &[Population]| fv(res, 5) / fv(res, 0);
+        assert!(ratio(&treanor) > ratio(&boltzmann));
+        let ratio = |res: &[Population]| fv(res, 1) / fv(res, 0);
+        assert!((ratio(&treanor) / ratio(&boltzmann) - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,209 @@
+use std::f64::consts::PI;
+
+use crate::{
+    domain::constant::{C, H, U},
+    prelude::{DunhamCoefficients, DunhamExpansion},
+};
+
+type Params = Vec<Vec<f64>>;
+
+/// Nodes and weights of the 4 points Gauss-Legendre quadrature on `[-1, 1]`
+const GAUSS_LEGENDRE: [(f64, f64); 4] = [
+    (-0.861_136_311_594_052_6, 0.347_854_845_137_453_8),
+    (-0.339_981_043_584_856_3, 0.652_145_154_862_546_1),
+    (0.339_981_043_584_856_3, 0.652_145_154_862_546_1),
+    (0.861_136_311_594_052_6, 0.347_854_845_137_453_8),
+];
+
+/// Rydberg-Klein-Rees potential energy curve
+///
+/// The classical turning points are `r = √(f^2 + f/g) ± f` with
+/// `f = √(h/(8π^2cμ)) ∫ dv'/√(G(v)-G(v'))` and
+/// `g = √(8π^2cμ/h) ∫ Bv' dv'/√(G(v)-G(v'))` from `v' = -1/2` to `v`.
+#[derive(Debug, Clone)]
+pub struct Rkr {
+    mu: f64,
+    params: Params,
+    vs: Vec<f64>,
+}
+
+impl Rkr {
+    /// Returns the new RKR instance
+    ///
+    /// # Arguments
+    ///
+    /// * `mu` - `μ`: Reduced mass (u)
+    /// * `dunham_coefficients` - Dunham coefficients of the electronic state
+    /// * `vs` - Vibrational levels of the turning points
+    pub fn new(mu: f64, dunham_coefficients: &DunhamCoefficients, vs: Vec<f64>) -> Self {
+        Self {
+            mu,
+            params: dunham_coefficients.to_vec(),
+            vs,
+        }
+    }
+
+    /// Returns `(rmin, rmax)`: Classical turning points of the vibrational level `v` (Å),
+    /// both `re = √(h/(8π^2cμBe))` at the bottom of the well `v = -1/2`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{DunhamCoefficients, Rkr, SpectroscopicConstants};
+    /// // N₂ X¹Σg+
+    /// let y: DunhamCoefficients = SpectroscopicConstants {
+    ///     we: 2358.57,
+    ///     wexe: 14.324,
+    ///     be: 1.99824,
+    ///     alpha_e: 0.017318,
+    ///     ..Default::default()
+    /// }
+    /// .into();
+    /// let (rmin, rmax) = Rkr::new(7.00153700, &y, vec![]).turning_points(0.0);
+    /// assert!((rmin - 1.0552).abs() < 1e-3);
+    /// assert!((rmax - 1.1456).abs() < 1e-3);
+    /// ```
+    pub fn turning_points(&self, v: f64) -> (f64, f64) {
+        // `h/(8π^2cμ)` (Å^2 cm^-1)
+        let k = H / (8.0 * PI.powi(2) * C * 100.0 * self.mu * U) * 1.0e20;
+        let g = |v: f64| {
+            DunhamExpansion::new(v, 0.0, self.params.clone())
+                .g_v()
+                .unwrap()
+        };
+        let b = |v: f64| DunhamExpansion::new(v, 0.0, self.params.clone()).b_v();
+
+        // `v' = v - s^2` removes the singularity at `v' = v`
+        let (gv, s) = (g(v), (v + 0.5).max(0.0).sqrt());
+        if s == 0.0 {
+            // `f = 0` and `f/g -> h/(8π^2cμBe)`
+            let re = (k / b(-0.5)).sqrt();
+            return (re, re);
+        }
+        let n = 64;
+        let h = s / n as f64;
+        let (mut f_int, mut g_int) = (0.0, 0.0);
+        for i in 0..n {
+            for (x, w) in GAUSS_LEGENDRE {
+                let t = h * (i as f64 + (x + 1.0) / 2.0);
+                let vv = v - t * t;
+                let d = 2.0 * t * w * h / 2.0 / (gv - g(vv)).sqrt();
+                f_int += d;
+                g_int += b(vv) * d;
+            }
+        }
+
+        let f = k.sqrt() * f_int;
+        let g = g_int / k.sqrt();
+        let r = (f.powi(2) + f / g).sqrt();
+        (r - f, r + f)
+    }
+
+    /// Returns the potential energy curve `[(r (Å), U(r) (cm^-1))]` in ascending order of
+    /// `r`, consuming `self` value
+    ///
+    /// `U(r)` is measured from the minimum `U(re) = 0`, where `re = √(h/(8π^2cμBe))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emission_spectrum_lib_rs::prelude::{DunhamCoefficients, Rkr};
+    /// let y = DunhamCoefficients::new()
+    ///     .with(1, 0, 2358.57)
+    ///     .with(2, 0, -14.324)
+    ///     .with(0, 1, 1.99824)
+    ///     .with(1, 1, -0.017318);
+    /// let res = Rkr::new(7.00153700, &y, vec![0.0, 1.0]).eval();
+    /// assert_eq!(res.len(), 5);
+    /// assert_eq!(res[2].1, 0.0);
+    /// assert!(res.windows(2).all(|v| v[0].0 < v[1].0));
+    /// ```
+    pub fn eval(self) -> Vec<(f64, f64)> {
+        let k = H / (8.0 * PI.powi(2) * C * 100.0 * self.mu * U) * 1.0e20;
+        let e = DunhamExpansion::new(-0.5, 0.0, self.params.clone());
+        let (g0, re) = (e.g_v().unwrap(), (k / e.b_v()).sqrt());
+
+        let points: Vec<(f64, f64, f64)> = self
+            .vs
+            .iter()
+            .map(|&v| {
+                let (rmin, rmax) = self.turning_points(v);
+                let u = DunhamExpansion::new(v, 0.0, self.params.clone())
+                    .g_v()
+                    .unwrap()
+                    - g0;
+                (rmin, rmax, u)
+            })
+            .collect();
+
+        let mut res: Vec<(f64, f64)> = points
+            .iter()
+            .map(|&(rmin, _, u)| (rmin, u))
+            .chain([(re, 0.0)])
+            .chain(points.iter().map(|&(_, rmax, u)| (rmax, u)))
+            .collect();
+        res.sort_by(|a, b| a.0.total_cmp(&b.0));
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::Rkr;
+    use crate::{
+        domain::constant::{C, H, U},
+        prelude::DunhamCoefficients,
+    };
+
+    #[test]
+    fn harmonic() {
+        // Harmonic oscillator and rigid rotor: `f = 2√(h(v+1/2)/(8π^2cμωe))`, `f/g = re^2`
+        let (mu, we, be) = (7.0, 2000.0, 2.0);
+        let k = H / (8.0 * PI.powi(2) * C * 100.0 * mu * U) * 1.0e20;
+        let re2 = k / be;
+        let y = DunhamCoefficients::new().with(1, 0, we).with(0, 1, be);
+        let rkr = Rkr::new(mu, &y, vec![]);
+        assert_eq!(rkr.turning_points(-0.5), (re2.sqrt(), re2.sqrt()));
+        for v in (0..10).map(|v| v as f64) {
+            let f = 2.0 * (k * (v + 0.5) / we).sqrt();
+            let r = (f * f + re2).sqrt();
+            let (rmin, rmax) = rkr.turning_points(v);
+            assert!((rmin - (r - f)).abs() < 1e-9);
+            assert!((rmax - (r + f)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn morse() {
+        // Morse potential with Pekeris `αe`
+        let (mu, we, wexe) = (7.0, 2000.0, 15.0);
+        let k = H / (8.0 * PI.powi(2) * C * 100.0 * mu * U) * 1.0e20;
+        let re: f64 = 1.1;
+        let be = k / re.powi(2);
+        let alpha = 6.0 * (wexe * be.powi(3)).sqrt() / we - 6.0 * be.powi(2) / we;
+        let de = we.powi(2) / (4.0 * wexe);
+        let a = (2.0 * mu * U * 100.0 * C * wexe / H).sqrt() * 2.0 * PI * 1.0e-10;
+        let morse = |r: f64| de * (1.0 - (-a * (r - re)).exp()).powi(2);
+
+        let y = DunhamCoefficients::new()
+            .with(1, 0, we)
+            .with(2, 0, -wexe)
+            .with(0, 1, be)
+            .with(1, 1, -alpha);
+        let res = Rkr::new(mu, &y, (0..5).map(|v| v as f64).collect()).eval();
+        for (r, u) in res.into_iter().filter(|&(_, u)| u > 0.0) {
+            assert!((morse(r) - u).abs() / u < 3e-3);
+        }
+
+        // The width `rmax - rmin = 2f` depends only on `G(v)`, exact for Morse
+        let rkr = Rkr::new(mu, &y, vec![]);
+        for v in (0..20).map(|v| v as f64) {
+            let (rmin, rmax) = rkr.turning_points(v);
+            let x = ((we * (v + 0.5) - wexe * (v + 0.5).powi(2)) / de).sqrt();
+            let width = ((1.0 + x) / (1.0 - x)).ln() / a;
+            assert!(((rmax - rmin) / width - 1.0).abs() < 1e-6);
+        }
+    }
+}